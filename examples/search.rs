@@ -1,7 +1,7 @@
 use std::io::{stdin, Write};
 
 use outertube::{
-    search::{ChannelResult, ShortResult, VideoResult},
+    search::{ChannelResult, MixResult, MovieResult, PlaylistResult, ShortResult, VideoResult},
     ClientBuilder,
 };
 
@@ -24,8 +24,15 @@ async fn main() -> Result<(), anyhow::Error> {
 
     loop {
         for channel in &search_results.channels {
-            let ChannelResult { id, name, url } = channel;
-            println!("-- Channel --\n\nID: {id},\nName: {name},\nURL: {url}\n");
+            let ChannelResult {
+                id,
+                name,
+                url,
+                handle,
+                ..
+            } = channel;
+            let handle = handle.as_deref().unwrap_or("None");
+            println!("-- Channel --\n\nID: {id},\nName: {name},\nHandle: {handle},\nURL: {url}\n");
         }
         for video in &search_results.videos {
             let VideoResult {
@@ -34,20 +41,57 @@ async fn main() -> Result<(), anyhow::Error> {
                 title,
                 channel,
                 view_count,
-                thumbnails: _,
+                ..
             } = video;
+            let view_count = view_count.map_or_else(|| "Unknown".to_string(), |x| x.to_string());
             println!("-- Video --\n\nID: {id},\nTitle: {title},\nChannel: {channel},\nURL: {url},\nviews: {view_count}\n");
         }
 
+        for playlist in &search_results.playlists {
+            let PlaylistResult {
+                id,
+                url,
+                title,
+                channel,
+                video_count,
+                ..
+            } = playlist;
+            let channel = channel.as_deref().unwrap_or("Unknown");
+            let video_count = video_count.map_or_else(|| "Unknown".to_string(), |x| x.to_string());
+            println!(
+                "-- Playlist --\n\nID: {id},\nTitle: {title},\nChannel: {channel},\nURL: {url},\nvideos: {video_count}\n"
+            );
+        }
+
+        for movie in &search_results.movies {
+            let MovieResult {
+                id,
+                url,
+                title,
+                offer,
+                ..
+            } = movie;
+            let offer = offer.as_deref().unwrap_or("None");
+            println!("-- Movie --\n\nID: {id},\nTitle: {title},\nURL: {url},\nOffer: {offer}\n");
+        }
+
+        for mix in &search_results.mixes {
+            let MixResult { id, url, title, .. } = mix;
+            println!("-- Mix --\n\nID: {id},\nTitle: {title},\nURL: {url}\n");
+        }
+
         for short in &search_results.shorts {
             let ShortResult {
                 id,
                 headline: title,
                 url,
                 thumbnails: _,
+                view_count,
+                length: _,
             } = short;
 
-            println!("-- Short --\n\nID: {id},\nTitle: {title},\nURL: {url}\n");
+            let view_count = view_count.map_or_else(|| "Unknown".to_string(), |x| x.to_string());
+            println!("-- Short --\n\nID: {id},\nTitle: {title},\nURL: {url},\nviews: {view_count}\n");
         }
 
 
@@ -0,0 +1,330 @@
+//! Deciphering the signatures of formats that come with a `signatureCipher` instead of a
+//! URL.
+//!
+//! The player's `base.js` has a function that takes the scrambled signature and runs it
+//! through a fixed sequence of three transforms: reversing it, cutting characters off
+//! the front, and swapping the first character with another. The transforms live in a
+//! helper object with minified names that change with every player, so which is which is
+//! worked out from their bodies.
+
+use regex::Regex;
+
+use crate::{api::formats::Format, error::Error};
+
+/// The function that takes the signature apart, transforms it, and puts it back together:
+/// `Fta=function(a){a=a.split("");XE.Ys(a,49);XE.nC(a,10);return a.join("")}`.
+const DECIPHER_FUNCTION_PATTERN: &str = r#"function\([\w$]+\)\{\s*[\w$]+=[\w$]+\.split\(\s*""\s*\);(.+?);?\s*return\s+[\w$]+\.join\(\s*""\s*\)\s*\}"#;
+/// A call to a transform in the decipher function: `XE.Ys(a,49)` or `XE["Ys"](a,49)`.
+const TRANSFORM_CALL_PATTERN: &str = r#"^([\w$]+)(?:\.([\w$]+)|\["([\w$]+)"\])\([\w$]+,(\d+)\)$"#;
+/// A transform in the helper object: `Ys:function(a,b){...}`.
+const TRANSFORM_PATTERN: &str = r#""?([\w$]+)"?:function\([\w$]+(?:,[\w$]+)?\)\{([^}]*)\}"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Transform {
+    Reverse,
+    /// Remove this many characters from the front.
+    Splice(usize),
+    /// Swap the first character with the one at this index (wrapping around).
+    Swap(usize),
+}
+
+/// The transforms a player applies to signatures, extracted from its `base.js`. Players
+/// change every few weeks, so this should be made again for every new player.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureCipher {
+    transforms: Vec<Transform>,
+}
+
+impl SignatureCipher {
+    /// Extract the transforms from the source of a player's `base.js`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Cipher`] if the decipher function or its transforms can't be found,
+    /// which means `YouTube` changed how the player is written.
+    pub fn from_player(player_js: &str) -> Result<Self, Error> {
+        let pattern = |x: &str| Regex::new(x).expect("cipher patterns are valid");
+
+        let body = pattern(&format!("(?s){DECIPHER_FUNCTION_PATTERN}"))
+            .captures(player_js)
+            .and_then(|x| x.get(1))
+            .ok_or_else(|| Error::Cipher("the decipher function wasn't found in the player".into()))?
+            .as_str();
+
+        let call_pattern = pattern(TRANSFORM_CALL_PATTERN);
+        let mut calls = Vec::new();
+        for statement in body.split(';').map(str::trim).filter(|x| !x.is_empty()) {
+            let call = call_pattern.captures(statement).ok_or_else(|| {
+                Error::Cipher(format!("unknown statement in the decipher function: {statement}"))
+            })?;
+            let object = call[1].to_string();
+            let method = call
+                .get(2)
+                .or_else(|| call.get(3))
+                .map_or("", |x| x.as_str())
+                .to_string();
+            let argument = call[4]
+                .parse::<usize>()
+                .map_err(|_| Error::Cipher(format!("bad transform argument: {statement}")))?;
+            calls.push((object, method, argument));
+        }
+
+        // Every transform is in the same helper object.
+        let object = calls
+            .first()
+            .map(|(object, ..)| object.clone())
+            .ok_or_else(|| Error::Cipher("the decipher function doesn't transform anything".into()))?;
+        if calls.iter().any(|(x, ..)| *x != object) {
+            return Err(Error::Cipher(
+                "the decipher function uses more than one helper object".into(),
+            ));
+        }
+
+        let helper = pattern(&format!(r"(?s)var {}=\{{(.*?)\}};", regex::escape(&object)))
+            .captures(player_js)
+            .and_then(|x| x.get(1))
+            .ok_or_else(|| Error::Cipher(format!("the helper object '{object}' wasn't found in the player")))?
+            .as_str();
+
+        let methods = pattern(TRANSFORM_PATTERN)
+            .captures_iter(helper)
+            .map(|x| (x[1].to_string(), x[2].to_string()))
+            .collect::<Vec<_>>();
+
+        let transforms = calls
+            .into_iter()
+            .map(|(_, method, argument)| {
+                let (_, body) = methods.iter().find(|(name, _)| *name == method).ok_or_else(|| {
+                    Error::Cipher(format!("the transform '{object}.{method}' wasn't found"))
+                })?;
+
+                if body.contains("reverse") {
+                    Ok(Transform::Reverse)
+                } else if body.contains("splice") {
+                    Ok(Transform::Splice(argument))
+                } else if body.contains('%') {
+                    Ok(Transform::Swap(argument))
+                } else {
+                    Err(Error::Cipher(format!(
+                        "unknown transform '{object}.{method}': {body}"
+                    )))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { transforms })
+    }
+
+    /// Unscramble a signature (the `s` of a `signatureCipher`).
+    #[must_use]
+    pub fn decipher(&self, signature: &str) -> String {
+        let mut signature = signature.chars().collect::<Vec<_>>();
+        for transform in &self.transforms {
+            match *transform {
+                Transform::Reverse => signature.reverse(),
+                Transform::Splice(x) => {
+                    signature.drain(..x.min(signature.len()));
+                }
+                Transform::Swap(x) if !signature.is_empty() => {
+                    let x = x % signature.len();
+                    signature.swap(0, x);
+                }
+                Transform::Swap(_) => {}
+            }
+        }
+
+        signature.into_iter().collect()
+    }
+
+    /// Turn a `signatureCipher` (`s=...&sp=sig&url=...`) into a playable URL.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Cipher`] if there's no signature or URL in it.
+    pub fn decipher_url(&self, signature_cipher: &str) -> Result<String, Error> {
+        let mut signature = None;
+        let mut parameter = "signature".to_string();
+        let mut url = None;
+        for (key, value) in signature_cipher.split('&').filter_map(|x| x.split_once('=')) {
+            let value = urlencoding::decode(value)
+                .map_err(|e| Error::Cipher(format!("the signature cipher isn't valid UTF-8: {e}")))?
+                .into_owned();
+            match key {
+                "s" => signature = Some(value),
+                "sp" => parameter = value,
+                "url" => url = Some(value),
+                _ => {}
+            }
+        }
+
+        let signature =
+            signature.ok_or_else(|| Error::Cipher("no signature in the signature cipher".into()))?;
+        let url = url.ok_or_else(|| Error::Cipher("no URL in the signature cipher".into()))?;
+        let separator = if url.contains('?') { '&' } else { '?' };
+
+        Ok(format!(
+            "{url}{separator}{parameter}={}",
+            urlencoding::encode(&self.decipher(&signature))
+        ))
+    }
+}
+
+impl Format {
+    /// The URL to stream the format from, deciphering its signature if it has one.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Cipher`] if the format has neither a URL nor a signature cipher, or the
+    /// signature cipher is malformed.
+    pub fn playable_url(&self, cipher: &SignatureCipher) -> Result<String, Error> {
+        match (&self.url, &self.signature_cipher) {
+            (Some(url), _) => Ok(url.clone()),
+            (None, Some(signature_cipher)) => cipher.decipher_url(signature_cipher),
+            (None, None) => Err(Error::Cipher(format!("format {} has no URL", self.itag))),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    // These players are synthetic, see the comment at the top of them. The expected
+    // signatures were worked out by running them with Node. `test_real_players` checks
+    // snapshots of real ones and `test_current_player` the one in use right now.
+    const PLAYER_A: &str = include_str!("../../test_data/players/synthetic_a.js");
+    const PLAYER_B: &str = include_str!("../../test_data/players/synthetic_b.js");
+    const SIGNATURE: &str =
+        "AOq0QJ8wRQIhAKzjRSyY5m6NgPXkqRSSOsDJqTJZ3bhBDOhSYhXW2Vu5AiBL3T5T-dxbbTHgEpRP8A7DLc0pP8Y8L5ZwzxRR2xXHMw==";
+
+    #[test]
+    fn test_extract_transforms() {
+        let cipher = SignatureCipher::from_player(PLAYER_A).unwrap();
+        assert_eq!(
+            cipher.transforms,
+            [
+                Transform::Swap(49),
+                Transform::Reverse,
+                Transform::Splice(3),
+                Transform::Swap(6),
+                Transform::Reverse,
+                Transform::Splice(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decipher() {
+        let cipher = SignatureCipher::from_player(PLAYER_A).unwrap();
+        assert_eq!(
+            cipher.decipher(SIGNATURE),
+            "q0QJ8wRQIhAKzjRSyY5m6NgPXkqRSSOsDJqTJZ3bhBDOhSYAXW2Vu5AiBL3T5T-dxbbTHgEpRP8A7DLc0pP8Y8L5ZwzxMR2xXHR"
+        );
+
+        // Bracket calls and a '$' in the helper's name.
+        let cipher = SignatureCipher::from_player(PLAYER_B).unwrap();
+        assert_eq!(
+            cipher.decipher(SIGNATURE),
+            "qAQJ8wRQIhAKzjRSyY5m6NgPXkqRSSOsDJqTJZ3bhBDOhSYhXW2Vu5AiBL=T5T-dxbbTHgEpRP8A7DLc0pP8Y8=5ZwzxRR2xXHMw3"
+        );
+    }
+
+    #[test]
+    fn test_decipher_url() {
+        let cipher = SignatureCipher::from_player(PLAYER_A).unwrap();
+        let signature_cipher = format!(
+            "s={}&sp=sig&url=https%3A%2F%2Frr1---sn-example.googlevideo.com%2Fvideoplayback%3Fitag%3D137",
+            urlencoding::encode(SIGNATURE)
+        );
+        assert_eq!(
+            cipher.decipher_url(&signature_cipher).unwrap(),
+            format!(
+                "https://rr1---sn-example.googlevideo.com/videoplayback?itag=137&sig={}",
+                urlencoding::encode(&cipher.decipher(SIGNATURE))
+            )
+        );
+        assert!(matches!(cipher.decipher_url("sp=sig"), Err(Error::Cipher(_))));
+    }
+
+    #[test]
+    fn test_changed_player() {
+        let Err(Error::Cipher(message)) = SignatureCipher::from_player("var a=function(b){return b};") else {
+            panic!("Expected the decipher function to be missing");
+        };
+        assert!(message.contains("decipher function"));
+
+        // The helper object was renamed without the calls being updated.
+        let player = PLAYER_A.replace("var XE=", "var YE=");
+        let Err(Error::Cipher(message)) = SignatureCipher::from_player(&player) else {
+            panic!("Expected the helper object to be missing");
+        };
+        assert!(message.contains("'XE'"));
+    }
+
+    /// Every real player snapshot in `test_data/players/real`, with its recorded
+    /// input/output pairs. See the README there.
+    pub(crate) fn real_players() -> Vec<(String, String, serde_json::Value)> {
+        let directory = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data/players/real");
+        let mut players = Vec::new();
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|x| x == "js") {
+                let id = path.file_stem().unwrap().to_string_lossy().into_owned();
+                let player = std::fs::read_to_string(&path).unwrap();
+                let pairs = std::fs::read_to_string(path.with_extension("json"))
+                    .unwrap_or_else(|_| panic!("No recorded pairs for player {id}"));
+                players.push((id, player, serde_json::from_str(&pairs).unwrap()));
+            }
+        }
+        players
+    }
+
+    /// The recorded `(input, output)` pairs under `key`.
+    pub(crate) fn recorded_pairs<'a>(pairs: &'a serde_json::Value, key: &str) -> Vec<(&'a str, &'a str)> {
+        pairs[key]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|x| (x[0].as_str().unwrap(), x[1].as_str().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_real_players() {
+        for (id, player, pairs) in real_players() {
+            let cipher = SignatureCipher::from_player(&player).unwrap_or_else(|e| panic!("Player {id}: {e}"));
+            for (signature, expected) in recorded_pairs(&pairs, "signatures") {
+                assert_eq!(cipher.decipher(signature), expected, "Player {id}");
+            }
+        }
+    }
+
+    /// Download the `base.js` of the player `YouTube` is using right now.
+    pub(crate) async fn current_player() -> String {
+        let http_client = crate::ClientBuilder::new().build().unwrap().get_http_client();
+        let get = |url: String| {
+            let http_client = http_client.clone();
+            async move { http_client.get(url).send().await.unwrap().text().await.unwrap() }
+        };
+
+        // The slashes are escaped in the script.
+        let iframe_api = get("https://www.youtube.com/iframe_api".into()).await;
+        let player_id = &Regex::new(r"/s\\?/player\\?/([\w-]+)\\?/")
+            .unwrap()
+            .captures(&iframe_api)
+            .expect("No player ID in the iframe API")[1];
+        get(format!(
+            "https://www.youtube.com/s/player/{player_id}/player_ias.vflset/en_US/base.js"
+        ))
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_current_player() {
+        let cipher = SignatureCipher::from_player(&current_player().await).unwrap();
+        assert!(!cipher.transforms.is_empty());
+        let signature = cipher.decipher(SIGNATURE);
+        assert!(signature.len() <= SIGNATURE.len());
+        assert!(signature.chars().all(|x| SIGNATURE.contains(x)));
+    }
+}
@@ -0,0 +1,433 @@
+//! Parsing the counts `YouTube` shows (views, subscribers, videos, etc.) in whatever
+//! language the client asked for.
+//!
+//! Numbers are written a lot of different ways, but two things hold for every language:
+//! an exact count never has a fractional part, and an abbreviated one always comes
+//! with a word or symbol for its size. So a separator followed by three digits in an
+//! unabbreviated count is a thousands separator, and the last separator in an
+//! abbreviated count is the decimal point. The language is only needed to know what
+//! the abbreviations mean, as some of them mean different things in different
+//! languages (e.g. "B" is billions in English but thousands in Turkish).
+
+use serde_json::Value;
+
+use crate::{
+    api::{parse_accessibility_label, parse_text},
+    error::Error,
+    util::AsciiStr,
+};
+
+/// A count of views, subscribers, videos, etc. `YouTube` often abbreviates these (e.g.
+/// "1.2M"), so they aren't always exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Count {
+    pub value:     u64,
+    pub precision: Precision,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Precision {
+    /// The count was shown in full.
+    #[default]
+    Exact,
+    /// The count was abbreviated, only the first `significant_digits` digits of
+    /// [`Count::value`] are right (e.g. 2 for "1.2M").
+    Approximate { significant_digits: u32 },
+}
+
+impl Count {
+    #[must_use]
+    pub fn exact(value: u64) -> Self {
+        Self {
+            value,
+            precision: Precision::Exact,
+        }
+    }
+
+    #[must_use]
+    pub fn is_exact(&self) -> bool { self.precision == Precision::Exact }
+
+    /// The range the real count could be in. Abbreviated counts are rounded down by
+    /// `YouTube`, so e.g. "1.2M" is anything from 1,200,000 to 1,299,999.
+    #[must_use]
+    pub fn range(&self) -> std::ops::RangeInclusive<u64> {
+        match self.precision {
+            Precision::Exact => self.value..=self.value,
+            Precision::Approximate { significant_digits } => {
+                let digits = self.value.checked_ilog10().map_or(1, |x| x + 1);
+                let unit = 10u64.pow(digits.saturating_sub(significant_digits));
+                self.value..=self.value.saturating_add(unit - 1)
+            }
+        }
+    }
+
+    /// Replace an approximate count with `other` if it's the exact version of it.
+    fn refine(self, other: Self) -> Self {
+        if !self.is_exact() && other.is_exact() && self.range().contains(&other.value) {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl std::fmt::Display for Count {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.precision {
+            Precision::Exact => write!(f, "{}", self.value),
+            Precision::Approximate { .. } => write!(f, "~{}", self.value),
+        }
+    }
+}
+
+/// Characters used to group digits or as a decimal point.
+const SEPARATORS: &[char] = &[',', '.', '\'', '’', ' ', '\u{a0}', '\u{202f}', '٫', '٬'];
+
+/// Abbreviations and what they multiply by. Compared in lower case and without a trailing
+/// '.'.
+type Multipliers = &'static [(&'static str, u64)];
+
+const ENGLISH: Multipliers = &[("k", 1_000), ("m", 1_000_000), ("b", 1_000_000_000)];
+/// The abbreviations of every language that doesn't use the English ones.
+const MULTIPLIERS: &[(&[&str], Multipliers)] = &[
+    (
+        &["de"],
+        &[("tsd", 1_000), ("mio", 1_000_000), ("mrd", 1_000_000_000)],
+    ),
+    (
+        &["fr"],
+        &[
+            ("k", 1_000),
+            ("m", 1_000_000),
+            ("md", 1_000_000_000),
+            ("mrd", 1_000_000_000),
+        ],
+    ),
+    (&["es"], &[("mil", 1_000), ("m", 1_000_000), ("mill", 1_000_000)]),
+    (
+        &["pt"],
+        &[("mil", 1_000), ("mi", 1_000_000), ("bi", 1_000_000_000)],
+    ),
+    (
+        &["it"],
+        &[
+            ("k", 1_000),
+            ("mila", 1_000),
+            ("mln", 1_000_000),
+            ("mld", 1_000_000_000),
+        ],
+    ),
+    (
+        &["nl"],
+        &[("k", 1_000), ("mln", 1_000_000), ("mld", 1_000_000_000)],
+    ),
+    (
+        &["ru", "be", "kk"],
+        &[("тыс", 1_000), ("млн", 1_000_000), ("млрд", 1_000_000_000)],
+    ),
+    (
+        &["uk"],
+        &[("тис", 1_000), ("млн", 1_000_000), ("млрд", 1_000_000_000)],
+    ),
+    (
+        &["pl"],
+        &[("tys", 1_000), ("mln", 1_000_000), ("mld", 1_000_000_000)],
+    ),
+    (
+        &["cs", "sk"],
+        &[("tis", 1_000), ("mil", 1_000_000), ("mld", 1_000_000_000)],
+    ),
+    (
+        &["tr"],
+        &[
+            ("b", 1_000),
+            ("bin", 1_000),
+            ("mn", 1_000_000),
+            ("mr", 1_000_000_000),
+        ],
+    ),
+    (&["id"], &[("rb", 1_000), ("jt", 1_000_000), ("m", 1_000_000_000)]),
+    (
+        &["vi"],
+        &[
+            ("n", 1_000),
+            ("k", 1_000),
+            ("tr", 1_000_000),
+            ("t", 1_000_000_000),
+        ],
+    ),
+    (
+        &["sv"],
+        &[("tn", 1_000), ("mn", 1_000_000), ("md", 1_000_000_000)],
+    ),
+    (
+        &["da"],
+        &[("t", 1_000), ("mio", 1_000_000), ("mia", 1_000_000_000)],
+    ),
+    (
+        &["nb", "no"],
+        &[("k", 1_000), ("mill", 1_000_000), ("mrd", 1_000_000_000)],
+    ),
+    (
+        &["fi"],
+        &[("t", 1_000), ("milj", 1_000_000), ("mrd", 1_000_000_000)],
+    ),
+    (
+        &["el"],
+        &[("χιλ", 1_000), ("εκ", 1_000_000), ("δισ", 1_000_000_000)],
+    ),
+    (
+        &["ar"],
+        &[("ألف", 1_000), ("مليون", 1_000_000), ("مليار", 1_000_000_000)],
+    ),
+    (
+        &["hi"],
+        &[
+            ("हज़ार", 1_000),
+            ("लाख", 100_000),
+            ("करोड़", 10_000_000),
+            ("क॰", 10_000_000),
+        ],
+    ),
+    (
+        &["th"],
+        &[
+            ("พัน", 1_000),
+            ("หมื่น", 10_000),
+            ("แสน", 100_000),
+            ("ล้าน", 1_000_000),
+        ],
+    ),
+    (&["ja", "zh"], CJK),
+    (&["ko"], &[("천", 1_000), ("만", 10_000), ("억", 100_000_000)]),
+];
+/// Chinese and Japanese count in ten thousands.
+const CJK: Multipliers = &[
+    ("千", 1_000),
+    ("万", 10_000),
+    ("萬", 10_000),
+    ("亿", 100_000_000),
+    ("億", 100_000_000),
+];
+
+/// Abbreviations that are written straight after the number (e.g. "3.4万回視聴").
+fn is_unspaced_multiplier(ch: char) -> bool {
+    matches!(ch, '千' | '万' | '萬' | '亿' | '億' | '천' | '만' | '억')
+}
+
+fn multipliers(hl: &str) -> Multipliers {
+    let language = hl.split(['-', '_']).next().unwrap_or(hl).to_lowercase();
+    MULTIPLIERS
+        .iter()
+        .find(|(languages, _)| languages.contains(&language.as_str()))
+        .map_or(ENGLISH, |(_, multipliers)| multipliers)
+}
+
+/// Parse the count in a text object (e.g. `viewCountText`). Abbreviated counts are made
+/// exact with the object's accessibility label when it has the full number.
+pub(crate) fn parse_count_text(text: &Value, hl: &str) -> Result<Count, Error> {
+    let count = parse_text(text)
+        .ok_or_else(|| Error::JsonParse("No count found".into()))
+        .and_then(|x| parse_count(&x, hl))?;
+
+    Ok(parse_accessibility_count(text, hl).map_or(count, |x| count.refine(x)))
+}
+
+/// Parse the count in a text object's accessibility label, if it has one.
+pub(crate) fn parse_accessibility_count(text: &Value, hl: &str) -> Option<Count> {
+    let label = parse_accessibility_label(text)?;
+    // Labels usually spell out abbreviations (e.g. "1.2 million views"), which won't
+    // parse, and are left alone.
+    parse_count(label, hl).ok()
+}
+
+/// Parse a count like "1,234 views", "1.2M subscribers", "1,2 Mio. Aufrufe", "3,4 万
+/// 回視聴", or "1,5 k vues" written in the language `hl`. Text without any digits (e.g.
+/// "No views" or "Keine Aufrufe") is a count of zero.
+pub(crate) fn parse_count(text: &str, hl: &str) -> Result<Count, Error> {
+    let unparsable = || Error::JsonParse(format!("Unparsable count: \"{text}\""));
+
+    // Eastern Arabic digits are used in some languages.
+    let text_ascii = text
+        .chars()
+        .map(|ch| {
+            match ch {
+                '٠'..='٩' => char::from(b'0' + (ch as u32 - '٠' as u32) as u8),
+                _ => ch,
+            }
+        })
+        .collect::<String>();
+
+    let Some(start) = text_ascii.find(|ch: char| ch.is_ascii_digit()) else {
+        return Ok(Count::exact(0));
+    };
+
+    // Take the digits and separators, only keeping a space if there's more digits after it
+    // so the space before an abbreviation isn't taken as a separator.
+    let rest = &text_ascii[start..];
+    let mut number_end = 0;
+    for (i, ch) in rest.char_indices() {
+        if ch.is_ascii_digit() {
+            number_end = i + ch.len_utf8();
+        } else if !SEPARATORS.contains(&ch) {
+            break;
+        }
+    }
+    let (number, mut rest) = rest.split_at(number_end);
+
+    // Some languages write "thousand millions" (e.g. "1,2 mil M"), so keep multiplying
+    // while there are abbreviations.
+    let multipliers = multipliers(hl);
+    let mut multiplier = 1u64;
+    loop {
+        rest = rest.trim_start_matches(|ch: char| ch.is_whitespace());
+        let token_len = match rest.chars().next() {
+            Some(ch) if is_unspaced_multiplier(ch) => ch.len_utf8(),
+            _ => {
+                rest.find(|ch: char| ch.is_whitespace() || ch.is_ascii_digit())
+                    .unwrap_or(rest.len())
+            }
+        };
+        let token = rest[..token_len].trim_end_matches('.').to_lowercase();
+
+        let Some((_, x)) = multipliers
+            .iter()
+            .find(|(abbreviation, _)| *abbreviation == token)
+        else {
+            break;
+        };
+        multiplier = multiplier.checked_mul(*x).ok_or_else(unparsable)?;
+        rest = &rest[token_len..];
+    }
+
+    let last_separator = number
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| matches!(ch, '.' | ',' | '٫'))
+        .map(|(i, ch)| (i, i + ch.len_utf8()));
+    let (whole, fraction) = match last_separator {
+        Some((i, end)) if multiplier > 1 => (&number[..i], &number[end..]),
+        // A group of anything but three digits after the last separator means it's a
+        // fraction, and the abbreviation that should have come with it wasn't understood.
+        Some((_, end)) if number[end..].len() != 3 => return Err(unparsable()),
+        _ => (number, ""),
+    };
+
+    let whole = whole.replace(SEPARATORS, "");
+    if !whole.is_ascii_digits() || !fraction.is_ascii_digits() {
+        return Err(unparsable());
+    }
+
+    let whole_value = whole.parse::<u64>().map_err(|_| unparsable())?;
+    let mut value = whole_value.checked_mul(multiplier).ok_or_else(unparsable)?;
+    if !fraction.is_empty() {
+        // Do the fraction in integers so e.g. "1.2M" is exactly 1,200,000.
+        let scale = 10u64.checked_pow(fraction.len() as u32).ok_or_else(unparsable)?;
+        let fraction = fraction.parse::<u64>().map_err(|_| unparsable())?;
+        let fraction_value = fraction.checked_mul(multiplier).ok_or_else(unparsable)? / scale;
+        value = value.checked_add(fraction_value).ok_or_else(unparsable)?;
+    }
+
+    let precision = if multiplier == 1 {
+        Precision::Exact
+    } else {
+        let shown_digits = whole.trim_start_matches('0').len() + fraction.len();
+        Precision::Approximate {
+            significant_digits: shown_digits.max(1) as u32,
+        }
+    };
+
+    Ok(Count { value, precision })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_count_english() {
+        assert_eq!(parse_count("No views", "en").unwrap().value, 0);
+        assert_eq!(parse_count("1 view", "en").unwrap().value, 1);
+        assert_eq!(parse_count("1,234,567 views", "en").unwrap().value, 1_234_567);
+        assert_eq!(parse_count("1.2M views", "en").unwrap().value, 1_200_000);
+        assert_eq!(parse_count("12K watching", "en").unwrap().value, 12_000);
+        assert_eq!(parse_count("1.25B views", "en-GB").unwrap().value, 1_250_000_000);
+        assert_eq!(parse_count("25 videos", "en").unwrap().value, 25);
+        // A fraction without an abbreviation can't be a count.
+        assert!(parse_count("1.5 lots of views", "en").is_err());
+        // Too big to fit.
+        assert!(parse_count("1.99999999999999999M views", "en").is_err());
+        assert!(parse_count("18446744073709551.9K views", "en").is_err());
+    }
+
+    #[test]
+    fn test_parse_count_localized() {
+        assert_eq!(parse_count("1,2 Mio. Aufrufe", "de").unwrap().value, 1_200_000);
+        assert_eq!(parse_count("1.234.567 Aufrufe", "de").unwrap().value, 1_234_567);
+        assert_eq!(parse_count("Keine Aufrufe", "de").unwrap().value, 0);
+        assert_eq!(parse_count("3,4 万 回視聴", "ja").unwrap().value, 34_000);
+        assert_eq!(parse_count("3.4万回視聴", "ja").unwrap().value, 34_000);
+        assert_eq!(parse_count("1,5 k vues", "fr").unwrap().value, 1_500);
+        assert_eq!(parse_count("1\u{202f}234 vues", "fr").unwrap().value, 1_234);
+        assert_eq!(
+            parse_count("1,2 mil M de visualizaciones", "es").unwrap().value,
+            1_200_000_000
+        );
+        assert_eq!(parse_count("12 mil visualizaciones", "es").unwrap().value, 12_000);
+        assert_eq!(
+            parse_count("1,2 mi de visualizações", "pt-BR").unwrap().value,
+            1_200_000
+        );
+        assert_eq!(parse_count("1,2 млн просмотров", "ru").unwrap().value, 1_200_000);
+        assert_eq!(parse_count("조회수 1.2만회", "ko").unwrap().value, 12_000);
+        assert_eq!(parse_count("1,2 B görüntüleme", "tr").unwrap().value, 1_200);
+        assert_eq!(
+            parse_count("1,2 M x ditonton", "id").unwrap().value,
+            1_200_000_000
+        );
+        assert_eq!(parse_count("1.2 लाख व्यूज़", "hi").unwrap().value, 120_000);
+        assert_eq!(parse_count("12,34,567 व्यूज़", "hi").unwrap().value, 1_234_567);
+        assert_eq!(parse_count("١٬٢٣٤ مشاهدة", "ar").unwrap().value, 1_234);
+        assert_eq!(parse_count("2,5 mil. zhlédnutí", "cs").unwrap().value, 2_500_000);
+    }
+
+    #[test]
+    fn test_count_precision() {
+        assert_eq!(
+            parse_count("1,234,567 views", "en").unwrap(),
+            Count::exact(1_234_567)
+        );
+        let count = parse_count("1.2M views", "en").unwrap();
+        assert_eq!(
+            count.precision,
+            Precision::Approximate {
+                significant_digits: 2,
+            }
+        );
+        assert_eq!(count.range(), 1_200_000..=1_299_999);
+        assert_eq!(count.to_string(), "~1200000");
+        assert_eq!(
+            parse_count("12K watching", "en").unwrap().range(),
+            12_000..=12_999
+        );
+
+        let text = json!({
+            "simpleText": "1.2M views",
+            "accessibility": { "accessibilityData": { "label": "1,234,567 views" } }
+        });
+        assert_eq!(parse_count_text(&text, "en").unwrap(), Count::exact(1_234_567));
+        // A label that doesn't match the text is ignored.
+        let text = json!({
+            "simpleText": "1.2M views",
+            "accessibility": { "accessibilityData": { "label": "2,345,678 views" } }
+        });
+        assert!(!parse_count_text(&text, "en").unwrap().is_exact());
+        let text = json!({
+            "simpleText": "1.2M views",
+            "accessibility": { "accessibilityData": { "label": "1.2 million views" } }
+        });
+        assert_eq!(parse_count_text(&text, "en").unwrap().value, 1_200_000);
+    }
+}
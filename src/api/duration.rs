@@ -0,0 +1,129 @@
+//! Parsing video lengths, either from the clock style text on thumbnails ("12:05") or
+//! from the spelled out accessibility label ("12 minutes, 5 seconds").
+
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::api::{parse_accessibility_label, parse_text, published::time_units};
+
+/// Parse the length in a text object like `lengthText`, falling back on its accessibility
+/// label. Returns `None` for text that isn't a length (e.g. "SHORTS" or "LIVE").
+pub(crate) fn parse_length(text: &Value, hl: &str) -> Option<Duration> {
+    parse_text(text)
+        .and_then(|x| parse_clock_duration(&x))
+        .or_else(|| parse_accessibility_label(text).and_then(|x| parse_duration_label(x, hl)))
+}
+
+/// Parse a clock style duration like "3:34" or "1:02:03". Some languages write these
+/// with dots instead (e.g. "1.02.03").
+pub(crate) fn parse_clock_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    let separator = [':', '.'].into_iter().find(|x| text.contains(*x))?;
+
+    let mut seconds = 0u64;
+    for (i, part) in text.split(separator).enumerate() {
+        if part.is_empty() || !part.bytes().all(|x| x.is_ascii_digit()) {
+            return None;
+        }
+        let value = part.parse::<u64>().ok()?;
+        // Only the first part can go past 59, so "1.2" of "1.2M" isn't taken as a length.
+        if i > 0 && (part.len() != 2 || value >= 60) {
+            return None;
+        }
+        seconds = seconds.checked_mul(60)?.checked_add(value)?;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Parse a spelled out duration like "1 hour, 2 minutes, 3 seconds" written in the
+/// language `hl`.
+pub(crate) fn parse_duration_label(label: &str, hl: &str) -> Option<Duration> {
+    let units = time_units(hl);
+    let label = label.to_lowercase();
+
+    let mut seconds = 0u64;
+    let mut found = false;
+    let mut rest = label.as_str();
+    while let Some(start) = rest.find(|ch: char| ch.is_ascii_digit()) {
+        rest = &rest[start..];
+        let number_len = rest.find(|ch: char| !ch.is_ascii_digit()).unwrap_or(rest.len());
+        let amount = rest[..number_len].parse::<u64>().ok()?;
+        rest = rest[number_len..].trim_start();
+
+        if let Some((_, unit)) = units.iter().find(|(word, _)| rest.starts_with(word)) {
+            seconds = seconds.checked_add(amount.checked_mul(*unit)?)?;
+            found = true;
+        }
+    }
+
+    found.then(|| Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_clock_duration() {
+        assert_eq!(parse_clock_duration("12:05"), Some(Duration::from_secs(725)));
+        assert_eq!(parse_clock_duration("1:02:03"), Some(Duration::from_secs(3723)));
+        assert_eq!(parse_clock_duration("0:59"), Some(Duration::from_secs(59)));
+        assert_eq!(parse_clock_duration("1.02.03"), Some(Duration::from_secs(3723)));
+        assert_eq!(parse_clock_duration("SHORTS"), None);
+        assert_eq!(parse_clock_duration("1.2M"), None);
+        assert_eq!(parse_clock_duration("1:2"), None);
+        assert_eq!(parse_clock_duration("1:75"), None);
+    }
+
+    #[test]
+    fn test_parse_duration_label() {
+        assert_eq!(
+            parse_duration_label("1 hour, 2 minutes, 3 seconds", "en"),
+            Some(Duration::from_secs(3723))
+        );
+        assert_eq!(
+            parse_duration_label("3 minutes, 33 seconds", "en-GB"),
+            Some(Duration::from_secs(213))
+        );
+        assert_eq!(
+            parse_duration_label("1 Stunde, 2 Minuten und 3 Sekunden", "de"),
+            Some(Duration::from_secs(3723))
+        );
+        assert_eq!(
+            parse_duration_label("12 minutes et 5 secondes", "fr"),
+            Some(Duration::from_secs(725))
+        );
+        assert_eq!(
+            parse_duration_label("1 時間 2 分 3 秒", "ja"),
+            Some(Duration::from_secs(3723))
+        );
+        assert_eq!(
+            parse_duration_label("1시간 2분 3초", "ko"),
+            Some(Duration::from_secs(3723))
+        );
+        assert_eq!(
+            parse_duration_label("2 минуты 5 секунд", "ru"),
+            Some(Duration::from_secs(125))
+        );
+        assert_eq!(parse_duration_label("Shorts", "en"), None);
+    }
+
+    #[test]
+    fn test_parse_length() {
+        let text = json!({
+            "simpleText": "3:33",
+            "accessibility": { "accessibilityData": { "label": "3 minutes, 33 seconds" } }
+        });
+        assert_eq!(parse_length(&text, "en"), Some(Duration::from_secs(213)));
+        let text = json!({
+            "runs": [{ "text": "SHORTS" }],
+            "accessibility": { "accessibilityData": { "label": "58 seconds" } }
+        });
+        assert_eq!(parse_length(&text, "en"), Some(Duration::from_secs(58)));
+        assert_eq!(parse_length(&json!({ "simpleText": "SHORTS" }), "en"), None);
+    }
+}
@@ -3,7 +3,11 @@ use std::fmt;
 use serde_json::{json, Value};
 use tracing::{event, Level};
 
-use crate::{client::Client, config::CONFIG, error::Error};
+use crate::{
+    client::{Client, ClientVariant},
+    config::{ClientContext, CONFIG, REFERER_YOUTUBE_MUSIC},
+    error::Error,
+};
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -39,15 +43,45 @@ impl fmt::Display for Endpoint {
 
 impl Endpoint {
     async fn post(self, client: &Client, data: Value) -> Result<Value, Error> {
+        self.post_as(client, client.client_context, data).await
+    }
+
+    /// Post as a different client than the one `client` was built for. Some endpoints
+    /// (e.g. the music ones) only work for specific clients.
+    async fn post_as(
+        self,
+        client: &Client,
+        client_context: ClientContext,
+        data: Value,
+    ) -> Result<Value, Error> {
         let endpoint = self.to_string();
-        // let url = match client.client_context.api_key {
-        //     Some(key) => format!("{}{endpoint}?key={key}&prettyPrint=false", CONFIG.base_url),
-        //     None => format!("{}{endpoint}?prettyPrint=false", CONFIG.base_url),
-        // };
+        let base_url = if client_context.referer == Some(REFERER_YOUTUBE_MUSIC) {
+            CONFIG.music_base_url
+        } else {
+            CONFIG.base_url
+        };
+
+        let url = if let Some(key) = client_context.api_key {
+            format!("{base_url}{endpoint}?key={key}&prettyPrint=false")
+        } else {
+            format!("{base_url}{endpoint}?prettyPrint=false")
+        };
 
-        let url = if let Some(key) = client.client_context.api_key { format!("{}{endpoint}?key={key}&prettyPrint=false", CONFIG.base_url) } else { format!("{}{endpoint}?prettyPrint=false", CONFIG.base_url) };
+        let mut request = client.get_http_client().post(url).json(&data);
+        // The default headers are for the client's own context, so they need to be replaced.
+        if client_context.id != client.client_context.id {
+            request = request
+                .header("X-Youtube-Client-Name", client_context.id.to_string())
+                .header("X-Youtube-Client-Version", client_context.version);
+            if let Some(referer) = client_context.referer {
+                request = request.header("Referer", referer).header("Origin", referer);
+            }
+            if let Some(user_agent) = client_context.user_agent {
+                request = request.header("User-Agent", user_agent);
+            }
+        }
 
-        let post_result = client.get_http_client().post(url).json(&data).send().await;
+        let post_result = request.send().await;
         match post_result {
             Ok(response) => {
                 event!(target: "innertube", Level::TRACE, "Successfully requested data from endpoint: {}", endpoint);
@@ -107,9 +141,9 @@ impl Endpoint {
     }
 }
 
-fn make_yt_context(client: &Client) -> Value {
-    let client_context = client.client_context;
+fn make_yt_context(client: &Client) -> Value { make_yt_context_as(client, client.client_context) }
 
+fn make_yt_context_as(client: &Client, client_context: ClientContext) -> Value {
     let mut context = json!({
         "hl": client.locale.hl,
         "clientName": client_context.name,
@@ -123,8 +157,8 @@ fn make_yt_context(client: &Client) -> Value {
     json!({ "client": context })
 }
 
-/// Ok, so look. `YouTube` uses protobuf for sending their search parameters and I have no
-/// way of getting their schema so proper parameters in the library cannot work. 
+/// `params` is the URL encoded, base64 encoded protobuf message `YouTube` calls `sp`. See
+/// [`crate::search::SearchFilters`] for building one.
 pub(crate) async fn search(client: &Client, query: &str, params: Option<&str>) -> Result<Value, Error> {
     let data = json! ({
         "query": urlencoding::encode(query),
@@ -144,6 +178,113 @@ pub(crate) async fn search_continuation(client: &Client, continuation: &str) ->
     Endpoint::Search.post(client, data).await
 }
 
+pub(crate) async fn browse(client: &Client, browse_id: &str, params: Option<&str>) -> Result<Value, Error> {
+    let mut data = json! ({
+        "browseId": browse_id,
+        "context": make_yt_context(client),
+    });
+    if let Some(params) = params {
+        data["params"] = Value::String(params.to_string());
+    }
+
+    Endpoint::Browse.post(client, data).await
+}
+
+pub(crate) async fn browse_continuation(client: &Client, continuation: &str) -> Result<Value, Error> {
+    let data = json! ({
+        "context": make_yt_context(client),
+        "continuation": continuation,
+    });
+
+    Endpoint::Browse.post(client, data).await
+}
+
+/// Get a video's details, whether it can be played, and the formats it can be streamed
+/// in.
+pub(crate) async fn player(client: &Client, video_id: &str) -> Result<Value, Error> {
+    let data = json! ({
+        "videoId": video_id,
+        "context": make_yt_context(client),
+        // Skip the "this video may be inappropriate" interstitials.
+        "contentCheckOk": true,
+        "racyCheckOk": true,
+    });
+
+    Endpoint::Player.post(client, data).await
+}
+
+/// Get query suggestions for a partial query from the same service the search bar uses.
+/// The response is JSONP, not JSON, so it's returned as text.
+pub(crate) async fn search_suggestions(client: &Client, query: &str) -> Result<String, Error> {
+    let mut params = vec![
+        ("client", "youtube"),
+        ("ds", "yt"),
+        ("hl", client.locale.hl.as_str()),
+        ("q", query),
+    ];
+    if let Some(gl) = &client.locale.gl {
+        params.push(("gl", gl.as_str()));
+    }
+
+    let response = client
+        .get_http_client()
+        .get(CONFIG.suggestions_url)
+        .query(&params)
+        .send()
+        .await?
+        .error_for_status()?;
+
+    Ok(response.text().await?)
+}
+
+pub(crate) async fn music_search_suggestions(client: &Client, input: &str) -> Result<Value, Error> {
+    let client_context = ClientVariant::WebRemix.into();
+    let data = json! ({
+        "input": input,
+        "context": make_yt_context_as(client, client_context),
+    });
+
+    Endpoint::MusicGetSearchSuggestions
+        .post_as(client, client_context, data)
+        .await
+}
+
+/// Search YouTube Music. `params` picks the tab (e.g. songs or albums), see
+/// [`crate::music::MusicFilter`].
+pub(crate) async fn music_search(client: &Client, query: &str, params: Option<&str>) -> Result<Value, Error> {
+    let client_context = ClientVariant::WebRemix.into();
+    let mut data = json! ({
+        "query": query,
+        "context": make_yt_context_as(client, client_context),
+    });
+    if let Some(params) = params {
+        data["params"] = Value::String(params.to_string());
+    }
+
+    Endpoint::Search.post_as(client, client_context, data).await
+}
+
+pub(crate) async fn music_get_queue(
+    client: &Client,
+    video_ids: Option<&[String]>,
+    playlist_id: Option<&str>,
+) -> Result<Value, Error> {
+    let client_context = ClientVariant::WebRemix.into();
+    let mut data = json! ({
+        "context": make_yt_context_as(client, client_context),
+    });
+    if let Some(video_ids) = video_ids {
+        data["videoIds"] = json!(video_ids);
+    }
+    if let Some(playlist_id) = playlist_id {
+        data["playlistId"] = Value::String(playlist_id.to_string());
+    }
+
+    Endpoint::MusicGetQueue
+        .post_as(client, client_context, data)
+        .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,4 +295,31 @@ mod tests {
         let client = ClientBuilder::new().build().unwrap();
         let _x = search(&client, "Linus Tech Tips", None).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_player() {
+        let client = ClientBuilder::new().build().unwrap();
+        let _x = player(&client, "dQw4w9WgXcQ").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_music_search() {
+        let client = ClientBuilder::new().build().unwrap();
+        let _x = music_search(&client, "never gonna give you up", None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_music_get_queue() {
+        let client = ClientBuilder::new().build().unwrap();
+        let video_ids = ["lYBUbBu4W08".to_string(), "yPYZpwSpKmA".to_string()];
+        let _x = music_get_queue(&client, Some(&video_ids), None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_music_search_suggestions() {
+        let client = ClientBuilder::new().build().unwrap();
+        let _x = music_search_suggestions(&client, "never gonna").await.unwrap();
+    }
 }
@@ -0,0 +1,336 @@
+use std::{
+    ops::RangeInclusive,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::Value;
+
+use crate::{
+    error::Error,
+    search::{ParseError, ParseMode},
+};
+
+/// The formats a video can be streamed in, from the `streamingData` of a `player`
+/// response.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StreamingFormats {
+    /// Formats with both video and audio. These only go up to 360p.
+    pub muxed:        Vec<Format>,
+    /// Adaptive formats with only video.
+    pub video_only:   Vec<Format>,
+    /// Adaptive formats with only audio.
+    pub audio_only:   Vec<Format>,
+    /// Formats that were left out because they couldn't be parsed. Always empty when
+    /// parsing with [`ParseMode::Strict`].
+    pub parse_errors: Vec<ParseError>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormatKind {
+    Muxed,
+    VideoOnly,
+    AudioOnly,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Format {
+    pub itag:              u32,
+    pub kind:              FormatKind,
+    /// `None` when the URL is in [`Format::signature_cipher`] instead.
+    pub url:               Option<String>,
+    /// The URL and the signature it needs, which has to be deciphered with the player
+    /// before the URL can be used.
+    pub signature_cipher:  Option<String>,
+    /// The mime type without the codecs (e.g. "video/mp4").
+    pub mime_type:         String,
+    /// The codecs from the mime type (e.g. `["avc1.42001E", "mp4a.40.2"]`).
+    pub codecs:            Vec<String>,
+    /// The peak bitrate in bits per second.
+    pub bitrate:           u64,
+    pub average_bitrate:   Option<u64>,
+    pub width:             Option<u32>,
+    pub height:            Option<u32>,
+    pub fps:               Option<u32>,
+    /// e.g. "1080p60".
+    pub quality_label:     Option<String>,
+    /// In hertz.
+    pub audio_sample_rate: Option<u32>,
+    pub audio_channels:    Option<u32>,
+    /// The size in bytes.
+    pub content_length:    Option<u64>,
+    pub approx_duration:   Option<Duration>,
+    /// The bytes of the stream's initialization segment, only for adaptive formats.
+    pub init_range:        Option<RangeInclusive<u64>>,
+    /// The bytes of the stream's segment index, only for adaptive formats.
+    pub index_range:       Option<RangeInclusive<u64>>,
+    pub last_modified:     Option<SystemTime>,
+}
+
+impl StreamingFormats {
+    /// Parse the formats in a `player` response. Videos that can't be streamed (e.g.
+    /// upcoming ones) have none. Formats that fail to parse are dealt with according to
+    /// `parse_mode`.
+    pub(crate) fn from_response(response: &Value, parse_mode: ParseMode) -> Result<Self, Error> {
+        let streaming_data = &response["streamingData"];
+        let mut formats = Self::default();
+
+        for (key, adaptive) in [("formats", false), ("adaptiveFormats", true)] {
+            for (i, format) in streaming_data[key].as_array().into_iter().flatten().enumerate() {
+                let error = match Format::parse(format, adaptive) {
+                    Ok(format) => {
+                        match format.kind {
+                            FormatKind::Muxed => formats.muxed.push(format),
+                            FormatKind::VideoOnly => formats.video_only.push(format),
+                            FormatKind::AudioOnly => formats.audio_only.push(format),
+                        }
+                        continue;
+                    }
+                    Err(e) => {
+                        ParseError {
+                            path:    format!("$.streamingData.{key}[{i}]"),
+                            value:   format.clone(),
+                            message: match e {
+                                Error::JsonParse(x) => x,
+                                e => e.to_string(),
+                            },
+                        }
+                    }
+                };
+
+                match parse_mode {
+                    ParseMode::Strict => return Err(Error::ItemParse(Box::new(error))),
+                    ParseMode::Lenient => formats.parse_errors.push(error),
+                }
+            }
+        }
+
+        Ok(formats)
+    }
+}
+
+impl Format {
+    fn parse(format: &Value, adaptive: bool) -> Result<Self, Error> {
+        let itag = format["itag"]
+            .as_u64()
+            .and_then(|x| u32::try_from(x).ok())
+            .ok_or(Error::JsonParse("No format itag found".into()))?;
+
+        let (mime_type, codecs) = format["mimeType"]
+            .as_str()
+            .map(parse_mime_type)
+            .ok_or_else(|| Error::JsonParse(format!("No mime type found for format {itag}")))?;
+
+        let kind = if !adaptive {
+            FormatKind::Muxed
+        } else if mime_type.starts_with("audio/") {
+            FormatKind::AudioOnly
+        } else {
+            FormatKind::VideoOnly
+        };
+
+        // Big numbers are given as strings.
+        let string_number = |key: &str| format[key].as_str().and_then(|x| x.parse::<u64>().ok());
+        let small_number = |key: &str| format[key].as_u64().and_then(|x| u32::try_from(x).ok());
+
+        Ok(Self {
+            itag,
+            kind,
+            url: format["url"].as_str().map(str::to_string),
+            signature_cipher: format["signatureCipher"]
+                .as_str()
+                .or_else(|| format["cipher"].as_str())
+                .map(str::to_string),
+            mime_type,
+            codecs,
+            bitrate: format["bitrate"].as_u64().unwrap_or_default(),
+            average_bitrate: format["averageBitrate"].as_u64(),
+            width: small_number("width"),
+            height: small_number("height"),
+            fps: small_number("fps"),
+            quality_label: format["qualityLabel"].as_str().map(str::to_string),
+            audio_sample_rate: string_number("audioSampleRate").and_then(|x| u32::try_from(x).ok()),
+            audio_channels: small_number("audioChannels"),
+            content_length: string_number("contentLength"),
+            approx_duration: string_number("approxDurationMs").map(Duration::from_millis),
+            init_range: parse_range(&format["initRange"]),
+            index_range: parse_range(&format["indexRange"]),
+            // In microseconds.
+            last_modified: string_number("lastModified").map(|x| UNIX_EPOCH + Duration::from_micros(x)),
+        })
+    }
+
+    #[must_use]
+    pub fn has_video(&self) -> bool { self.kind != FormatKind::AudioOnly }
+
+    #[must_use]
+    pub fn has_audio(&self) -> bool { self.kind != FormatKind::VideoOnly }
+}
+
+/// Split `video/mp4; codecs="avc1.42001E, mp4a.40.2"` into the mime type and codecs.
+fn parse_mime_type(mime_type: &str) -> (String, Vec<String>) {
+    let (essence, parameters) = mime_type.split_once(';').unwrap_or((mime_type, ""));
+    let codecs = parameters
+        .split(';')
+        .filter_map(|x| x.trim().strip_prefix("codecs="))
+        .flat_map(|x| x.trim_matches('"').split(','))
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    (essence.trim().to_string(), codecs)
+}
+
+/// Parse `{"start": "0", "end": "740"}`.
+fn parse_range(range: &Value) -> Option<RangeInclusive<u64>> {
+    let start = range["start"].as_str()?.parse().ok()?;
+    let end = range["end"].as_str()?.parse().ok()?;
+    Some(start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn streaming_data() -> Value {
+        json!({
+            "expiresInSeconds": "21540",
+            "formats": [{
+                "itag": 18,
+                "url": "https://rr1---sn-example.googlevideo.com/videoplayback?itag=18&n=aaaaaaaaaaaaaaaa",
+                "mimeType": "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"",
+                "bitrate": 503574,
+                "width": 640,
+                "height": 360,
+                "lastModified": "1694042131520834",
+                "contentLength": "13414133",
+                "quality": "medium",
+                "fps": 25,
+                "qualityLabel": "360p",
+                "averageBitrate": 503429,
+                "audioQuality": "AUDIO_QUALITY_LOW",
+                "approxDurationMs": "213159",
+                "audioSampleRate": "44100",
+                "audioChannels": 2
+            }],
+            "adaptiveFormats": [
+                {
+                    "itag": 137,
+                    "signatureCipher": "s=AOq0QJ8wRAIgabc&sp=sig&url=https://rr1---sn-example.googlevideo.com/videoplayback%3Fitag%3D137",
+                    "mimeType": "video/mp4; codecs=\"avc1.640028\"",
+                    "bitrate": 4397898,
+                    "width": 1920,
+                    "height": 1080,
+                    "initRange": { "start": "0", "end": "740" },
+                    "indexRange": { "start": "741", "end": "1256" },
+                    "lastModified": "1694042124000000",
+                    "contentLength": "80014155",
+                    "quality": "hd1080",
+                    "fps": 25,
+                    "qualityLabel": "1080p",
+                    "averageBitrate": 3003090,
+                    "approxDurationMs": "213120"
+                },
+                {
+                    "itag": 251,
+                    "url": "https://rr1---sn-example.googlevideo.com/videoplayback?itag=251",
+                    "mimeType": "audio/webm; codecs=\"opus\"",
+                    "bitrate": 141751,
+                    "initRange": { "start": "0", "end": "265" },
+                    "indexRange": { "start": "266", "end": "627" },
+                    "lastModified": "1694040834563483",
+                    "contentLength": "3437753",
+                    "quality": "tiny",
+                    "averageBitrate": 129025,
+                    "audioQuality": "AUDIO_QUALITY_MEDIUM",
+                    "approxDurationMs": "213161",
+                    "audioSampleRate": "48000",
+                    "audioChannels": 2,
+                    "loudnessDb": 7.66
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_parse_formats() {
+        let response = json!({ "streamingData": streaming_data() });
+        let formats = StreamingFormats::from_response(&response, ParseMode::Strict).unwrap();
+        assert_eq!(formats.muxed.len(), 1);
+        assert_eq!(formats.video_only.len(), 1);
+        assert_eq!(formats.audio_only.len(), 1);
+
+        let muxed = &formats.muxed[0];
+        assert_eq!(muxed.itag, 18);
+        assert_eq!(muxed.kind, FormatKind::Muxed);
+        assert!(muxed.has_video() && muxed.has_audio());
+        assert_eq!(muxed.mime_type, "video/mp4");
+        assert_eq!(muxed.codecs, ["avc1.42001E", "mp4a.40.2"]);
+        assert_eq!(
+            (muxed.width, muxed.height, muxed.fps),
+            (Some(640), Some(360), Some(25))
+        );
+        assert_eq!(muxed.audio_sample_rate, Some(44100));
+        assert_eq!(muxed.approx_duration, Some(Duration::from_millis(213_159)));
+        assert_eq!(muxed.init_range, None);
+        assert_eq!(
+            muxed.last_modified,
+            Some(UNIX_EPOCH + Duration::from_micros(1_694_042_131_520_834))
+        );
+
+        let video = &formats.video_only[0];
+        assert_eq!(video.kind, FormatKind::VideoOnly);
+        assert!(!video.has_audio());
+        assert_eq!(video.url, None);
+        assert!(video.signature_cipher.is_some());
+        assert_eq!(video.quality_label.as_deref(), Some("1080p"));
+        assert_eq!(video.average_bitrate, Some(3_003_090));
+        assert_eq!(video.init_range, Some(0..=740));
+        assert_eq!(video.index_range, Some(741..=1256));
+
+        let audio = &formats.audio_only[0];
+        assert_eq!(audio.kind, FormatKind::AudioOnly);
+        assert!(!audio.has_video());
+        assert_eq!(audio.mime_type, "audio/webm");
+        assert_eq!(audio.codecs, ["opus"]);
+        assert_eq!(audio.audio_channels, Some(2));
+        assert_eq!(audio.content_length, Some(3_437_753));
+        assert_eq!(audio.width, None);
+    }
+
+    #[test]
+    fn test_no_formats() {
+        let formats =
+            StreamingFormats::from_response(&json!({ "videoDetails": {} }), ParseMode::Strict).unwrap();
+        assert_eq!(formats, StreamingFormats::default());
+    }
+
+    #[test]
+    fn test_malformed_format() {
+        let mut streaming_data = streaming_data();
+        streaming_data["adaptiveFormats"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("mimeType");
+        let response = json!({ "streamingData": streaming_data });
+
+        let formats = StreamingFormats::from_response(&response, ParseMode::Lenient).unwrap();
+        assert_eq!(formats.muxed.len(), 1);
+        assert!(formats.video_only.is_empty());
+        assert_eq!(formats.audio_only.len(), 1);
+        assert_eq!(formats.parse_errors.len(), 1);
+        assert_eq!(formats.parse_errors[0].path, "$.streamingData.adaptiveFormats[0]");
+        assert_eq!(
+            formats.parse_errors[0].message,
+            "No mime type found for format 137"
+        );
+
+        let Err(Error::ItemParse(error)) = StreamingFormats::from_response(&response, ParseMode::Strict)
+        else {
+            panic!("Strict parsing should fail");
+        };
+        assert_eq!(error.path, "$.streamingData.adaptiveFormats[0]");
+    }
+}
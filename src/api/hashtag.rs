@@ -0,0 +1,332 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::Value;
+
+use crate::{
+    api::{
+        count::{parse_count, Count},
+        parse_text, protobuf,
+    },
+    client::Client,
+    config::Locale,
+    endpoints,
+    error::Error,
+    search::{
+        crawl_for_continuation, ContinuationMode, ItemParser, ParseError, ParseMode, SearchItem, ShortResult,
+        VideoResult,
+    },
+};
+
+/// The browse ID every hashtag feed is under. Which hashtag is picked with the params.
+const HASHTAG_BROWSE_ID: &str = "FEhashtag";
+
+/// The videos and shorts on a `/hashtag/<tag>` page.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HashtagFeed {
+    /// The hashtag without the leading '#'.
+    pub hashtag:       String,
+    /// How many videos use the hashtag, as shown in the header (e.g. "1.2M videos").
+    pub video_count:   Option<Count>,
+    /// How many channels use the hashtag, as shown in the header (e.g. "345K channels").
+    pub channel_count: Option<Count>,
+    pub videos:        Vec<VideoResult>,
+    pub shorts:        Vec<ShortResult>,
+    /// Videos and shorts that were left out because they couldn't be parsed.
+    pub parse_errors:  Vec<ParseError>,
+
+    continuation: Option<String>,
+    locale:       Locale,
+    parse_mode:   ParseMode,
+}
+
+impl HashtagFeed {
+    pub(crate) async fn get(client: &Client, hashtag: &str) -> Result<Self, Error> {
+        let hashtag = hashtag.trim().trim_start_matches('#');
+        let response = endpoints::browse(client, HASHTAG_BROWSE_ID, Some(&Self::params(hashtag))).await?;
+        Self::from_response(hashtag, &response, &client.locale, client.parse_mode)
+    }
+
+    /// `{93: {1: hashtag, 3: 1}}`, base64 encoded.
+    fn params(hashtag: &str) -> String {
+        let mut inner = protobuf::Writer::new();
+        inner.bytes(1, hashtag.as_bytes()).varint(3, 1);
+        let mut outer = protobuf::Writer::new();
+        outer.bytes(93, &inner.into_bytes());
+        STANDARD.encode(outer.into_bytes())
+    }
+
+    fn from_response(
+        hashtag: &str,
+        response: &Value,
+        locale: &Locale,
+        parse_mode: ParseMode,
+    ) -> Result<Self, Error> {
+        let contents = response
+            .get("contents")
+            .ok_or(Error::JsonParse("No 'contents' found".into()))?;
+
+        let mut feed = Self {
+            hashtag: hashtag.to_string(),
+            locale: locale.clone(),
+            parse_mode,
+            ..Default::default()
+        };
+        feed.parse_header(&response["header"]);
+        feed.parse_page(contents, "$.contents")?;
+
+        Ok(feed)
+    }
+
+    /// The counts are either in a `hashtagHeaderRenderer` as "1.2M videos • 345K
+    /// channels", or split up into the metadata parts of a `pageHeaderViewModel`. Which
+    /// count is which is told by the word next to it, as either can be left out.
+    fn parse_header(&mut self, header: &Value) {
+        let mut texts = Vec::new();
+        if let Some(text) = parse_text(&header["hashtagHeaderRenderer"]["hashtagInfoText"]) {
+            texts.push(text);
+        }
+        let rows = header["pageHeaderRenderer"]["content"]["pageHeaderViewModel"]["metadata"]
+            ["contentMetadataViewModel"]["metadataRows"]
+            .as_array();
+        for row in rows.into_iter().flatten() {
+            let parts = row["metadataParts"].as_array().into_iter().flatten();
+            texts.extend(parts.filter_map(|x| parse_text(&x["text"])));
+        }
+
+        let words = header_words(&self.locale.hl);
+        let parts = texts.iter().flat_map(|x| x.split('•')).map(str::trim);
+        for part in parts.filter(|x| x.contains(|ch: char| ch.is_ascii_digit()) && !x.starts_with('#')) {
+            let lowercase = part.to_lowercase();
+            let count = if words.videos.iter().any(|x| lowercase.contains(x)) {
+                &mut self.video_count
+            } else if words.channels.iter().any(|x| lowercase.contains(x)) {
+                &mut self.channel_count
+            } else {
+                continue;
+            };
+            *count = parse_count(part, &self.locale.hl).ok();
+        }
+    }
+
+    /// Add every video and short in `contents` and remember where the next page is.
+    /// `path` is where `contents` is in the response.
+    fn parse_page(&mut self, contents: &Value, path: &str) -> Result<(), Error> {
+        // Ads are dropped here, there's nowhere for them to go.
+        let mut parser = ItemParser::new(self.parse_mode, &self.locale, path);
+        let mut items = Vec::new();
+        parser.parse_items(contents, &mut items)?;
+        self.add_items(items);
+        self.parse_errors.append(&mut parser.errors);
+        self.continuation = crawl_for_continuation(contents).map(str::to_string);
+
+        Ok(())
+    }
+
+    fn add_items(&mut self, items: Vec<SearchItem>) {
+        for item in items {
+            match item {
+                SearchItem::Video(x) => self.videos.push(x),
+                SearchItem::Short(x) => self.shorts.push(x),
+                SearchItem::Shelf { items, .. } => self.add_items(items),
+                _ => {}
+            }
+        }
+    }
+
+    /// Get the next page of the feed, returns `false` if there isn't one.
+    pub(crate) async fn continue_feed(
+        &mut self,
+        client: &Client,
+        mode: ContinuationMode,
+    ) -> Result<bool, Error> {
+        let Some(continuation) = &self.continuation else {
+            return Ok(false);
+        };
+
+        let response = endpoints::browse_continuation(client, continuation).await?;
+        let actions = response
+            .get("onResponseReceivedActions")
+            .ok_or(Error::JsonParse("No continuation actions found".into()))?;
+
+        if mode == ContinuationMode::Replace {
+            self.videos.clear();
+            self.shorts.clear();
+            self.parse_errors.clear();
+        }
+        self.parse_page(actions, "$.onResponseReceivedActions")?;
+
+        Ok(true)
+    }
+}
+
+struct HeaderWords {
+    codes:    &'static [&'static str],
+    /// The start of the word for videos and channels, in every form it's written in (and
+    /// lowercase).
+    videos:   &'static [&'static str],
+    channels: &'static [&'static str],
+}
+
+/// The words the counts in a hashtag header are labelled with in the language `hl`.
+/// Languages that aren't known fall back on English.
+fn header_words(hl: &str) -> &'static HeaderWords {
+    let code = hl.split(['-', '_']).next().unwrap_or(hl).to_lowercase();
+    HEADER_WORDS
+        .iter()
+        .find(|x| x.codes.contains(&code.as_str()))
+        .unwrap_or(&HEADER_WORDS[0])
+}
+
+const HEADER_WORDS: &[HeaderWords] = &[
+    HeaderWords {
+        codes:    &["en"],
+        videos:   &["video"],
+        channels: &["channel"],
+    },
+    HeaderWords {
+        codes:    &["de"],
+        videos:   &["video"],
+        channels: &["kanal", "kanäle"],
+    },
+    HeaderWords {
+        codes:    &["fr"],
+        videos:   &["vidéo"],
+        channels: &["chaîne"],
+    },
+    HeaderWords {
+        codes:    &["es"],
+        videos:   &["video", "vídeo"],
+        channels: &["canal"],
+    },
+    HeaderWords {
+        codes:    &["pt"],
+        videos:   &["vídeo", "video"],
+        channels: &["canal", "canais"],
+    },
+    HeaderWords {
+        codes:    &["it"],
+        videos:   &["video"],
+        channels: &["canal"],
+    },
+    HeaderWords {
+        codes:    &["ru"],
+        videos:   &["видео"],
+        channels: &["канал"],
+    },
+    HeaderWords {
+        codes:    &["ja"],
+        videos:   &["動画"],
+        channels: &["チャンネル"],
+    },
+    HeaderWords {
+        codes:    &["ko"],
+        videos:   &["동영상"],
+        channels: &["채널"],
+    },
+    HeaderWords {
+        codes:    &["zh"],
+        videos:   &["视频", "影片"],
+        channels: &["频道", "頻道"],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::search::tests::{short_renderer, video_renderer};
+
+    #[test]
+    fn test_hashtag_params() {
+        assert_eq!(HashtagFeed::params("rust"), "6gUICgRydXN0GAE=");
+    }
+
+    #[test]
+    fn test_parse_hashtag_feed() {
+        let response = json!({
+            "header": { "hashtagHeaderRenderer": {
+                "hashtag": { "simpleText": "#rickroll" },
+                "hashtagInfoText": { "simpleText": "1.2M videos • 345K channels" }
+            } },
+            "contents": { "twoColumnBrowseResultsRenderer": { "tabs": [{ "tabRenderer": {
+                "content": { "richGridRenderer": { "contents": [
+                    { "richItemRenderer": { "content": { "videoRenderer": video_renderer() } } },
+                    { "richSectionRenderer": { "content": { "richShelfRenderer": {
+                        "title": { "runs": [{ "text": "Shorts" }] },
+                        "contents": [{ "richItemRenderer": { "content": { "reelItemRenderer": short_renderer() } } }]
+                    } } } },
+                    { "continuationItemRenderer": { "continuationEndpoint": {
+                        "continuationCommand": { "token": "next-page" }
+                    } } }
+                ] } }
+            } }] } }
+        });
+
+        let feed =
+            HashtagFeed::from_response("rickroll", &response, &Locale::default(), ParseMode::Strict).unwrap();
+        assert_eq!(feed.hashtag, "rickroll");
+        assert_eq!(feed.video_count.map(|x| x.value), Some(1_200_000));
+        assert_eq!(feed.channel_count.map(|x| x.value), Some(345_000));
+        assert_eq!(feed.videos.len(), 1);
+        assert_eq!(feed.shorts.len(), 1);
+        assert_eq!(feed.continuation.as_deref(), Some("next-page"));
+    }
+
+    #[test]
+    fn test_parse_localized_header() {
+        let mut feed = HashtagFeed {
+            locale: Locale::from("de-DE"),
+            ..Default::default()
+        };
+        feed.parse_header(&json!({ "hashtagHeaderRenderer": {
+            "hashtag": { "simpleText": "#rickroll" },
+            "hashtagInfoText": { "simpleText": "1,2 Mio. Videos • 345.000 Kanäle" }
+        } }));
+        assert_eq!(feed.video_count.map(|x| x.value), Some(1_200_000));
+        assert_eq!(feed.channel_count, Some(Count::exact(345_000)));
+    }
+
+    #[test]
+    fn test_parse_page_header() {
+        let mut feed = HashtagFeed::default();
+        feed.parse_header(
+            &json!({ "pageHeaderRenderer": { "content": { "pageHeaderViewModel": {
+            "metadata": { "contentMetadataViewModel": { "metadataRows": [{ "metadataParts": [
+                { "text": { "content": "25K videos" } },
+                { "text": { "content": "1,234 channels" } }
+            ] }] } }
+        } } } }),
+        );
+        assert_eq!(feed.video_count.map(|x| x.value), Some(25_000));
+        assert_eq!(feed.channel_count, Some(Count::exact(1_234)));
+    }
+
+    #[test]
+    fn test_parse_header_without_videos() {
+        let mut feed = HashtagFeed::default();
+        feed.parse_header(&json!({ "hashtagHeaderRenderer": {
+            "hashtag": { "simpleText": "#rickroll" },
+            "hashtagInfoText": { "simpleText": "345K channels" }
+        } }));
+        assert_eq!(feed.video_count, None);
+        assert_eq!(feed.channel_count.map(|x| x.value), Some(345_000));
+
+        // The other way around.
+        feed.parse_header(&json!({ "hashtagHeaderRenderer": {
+            "hashtagInfoText": { "simpleText": "1,234 channels • 25K videos" }
+        } }));
+        assert_eq!(feed.video_count.map(|x| x.value), Some(25_000));
+        assert_eq!(feed.channel_count, Some(Count::exact(1_234)));
+    }
+
+    #[tokio::test]
+    async fn test_hashtag() {
+        let client = crate::ClientBuilder::new().build().unwrap();
+        let mut feed = client.hashtag("#minecraft").await.unwrap();
+        assert!(!feed.videos.is_empty() || !feed.shorts.is_empty());
+        assert!(client
+            .continue_hashtag(&mut feed, ContinuationMode::Replace)
+            .await
+            .unwrap());
+    }
+}
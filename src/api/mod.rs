@@ -1,7 +1,18 @@
 use serde_json::Value;
 
+pub mod cipher;
+pub mod count;
+pub(crate) mod duration;
 pub(crate) mod endpoints;
+pub mod formats;
+pub mod hashtag;
+pub mod music;
+pub(crate) mod protobuf;
+pub mod published;
 pub mod search;
+pub mod suggestions;
+pub mod throttling;
+pub mod video;
 
 /// Recurses over an object and returns the first string it finds, or `None` if it never
 /// finds anything.
@@ -35,3 +46,31 @@ pub(crate) fn crawl_object_for_string<'a>(object: &'a Value, ignore: &[&str]) ->
         None
     }
 }
+
+/// Get the full text out of a text object. `YouTube` represents text either as
+/// `simpleText`, as a list of `runs` that need to be joined, or as `content` in view
+/// models.
+pub(crate) fn parse_text(object: &Value) -> Option<String> {
+    if let Some(s) = object
+        .get("simpleText")
+        .or_else(|| object.get("content"))
+        .and_then(Value::as_str)
+    {
+        return Some(s.to_string());
+    }
+
+    let runs = object.get("runs")?.as_array()?;
+    let text = runs
+        .iter()
+        .filter_map(|run| run.get("text").and_then(Value::as_str))
+        .collect::<String>();
+    Some(text)
+}
+
+/// Get the accessibility label of a text object, which often says more than the text
+/// (e.g. "3 minutes, 33 seconds" for "3:33").
+pub(crate) fn parse_accessibility_label(object: &Value) -> Option<&str> {
+    object["accessibility"]["accessibilityData"]["label"]
+        .as_str()
+        .or_else(|| object["accessibilityLabel"].as_str())
+}
@@ -0,0 +1,955 @@
+//! Searching YouTube Music. Its responses are made of `musicResponsiveListItemRenderer`s
+//! that share one layout for every kind of result, so what a result is has to be worked
+//! out from where it links to.
+
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::{
+    api::{
+        count::{parse_count, Count},
+        duration::parse_clock_duration,
+        parse_text,
+    },
+    client::Client,
+    endpoints,
+    error::Error,
+    search::{ParseError, ParseMode, Thumbnail},
+};
+
+/// Which tab of YouTube Music's search results to get.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MusicFilter {
+    /// The default tab with a bit of everything.
+    #[default]
+    All,
+    Songs,
+    Videos,
+    Albums,
+    Artists,
+    CommunityPlaylists,
+    FeaturedPlaylists,
+    Episodes,
+}
+
+impl MusicFilter {
+    /// The `params` YouTube Music uses for each tab.
+    fn params(self) -> Option<&'static str> {
+        Some(match self {
+            Self::All => return None,
+            Self::Songs => "EgWKAQIIAWoMEA4QChADEAQQCRAF",
+            Self::Videos => "EgWKAQIQAWoMEA4QChADEAQQCRAF",
+            Self::Albums => "EgWKAQIYAWoMEA4QChADEAQQCRAF",
+            Self::Artists => "EgWKAQIgAWoMEA4QChADEAQQCRAF",
+            Self::CommunityPlaylists => "EgeKAQQoAEABagwQDhAKEAMQBBAJEAU=",
+            Self::FeaturedPlaylists => "EgeKAQQoADgBagwQDhAKEAMQBBAJEAU=",
+            Self::Episodes => "EgWKAQJIAWoMEA4QChADEAQQCRAF",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MusicSearchResults {
+    /// The result YouTube Music shows in a card above the others, if any.
+    pub top_result: Option<MusicSearchItem>,
+    /// Every result in the order YouTube Music ranked them.
+    pub items:      Vec<MusicSearchItem>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MusicSearchItem {
+    Song(Song),
+    MusicVideo(MusicVideo),
+    Album(Album),
+    Artist(Artist),
+    CommunityPlaylist(CommunityPlaylist),
+    Episode(Episode),
+}
+
+/// An artist, album, or other page a result links to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MusicLink {
+    pub name: String,
+    /// The browse ID of the page. Not every name is a link (e.g. artists without a
+    /// channel).
+    pub id:   Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Song {
+    pub video_id:   String,
+    pub title:      String,
+    pub artists:    Vec<MusicLink>,
+    pub album:      Option<MusicLink>,
+    pub duration:   Option<Duration>,
+    /// Only shown for some songs.
+    pub year:       Option<u32>,
+    pub explicit:   bool,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MusicVideo {
+    pub video_id:   String,
+    pub title:      String,
+    pub artists:    Vec<MusicLink>,
+    /// Music videos are rarely linked to an album.
+    pub album:      Option<MusicLink>,
+    pub duration:   Option<Duration>,
+    pub year:       Option<u32>,
+    pub view_count: Option<Count>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Album {
+    pub browse_id:  String,
+    pub title:      String,
+    /// What sort of album it is as shown (e.g. "Album", "Single", or "EP").
+    pub album_type: Option<String>,
+    pub artists:    Vec<MusicLink>,
+    pub year:       Option<u32>,
+    /// The total length, if shown.
+    pub duration:   Option<Duration>,
+    pub explicit:   bool,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Artist {
+    pub browse_id:     String,
+    pub name:          String,
+    /// The subscriber or monthly audience count.
+    pub audience:      Option<Count>,
+    /// The count as shown, which says whether it's subscribers or monthly audience (e.g.
+    /// "4.5M monthly audience").
+    pub audience_text: Option<String>,
+    pub thumbnails:    Vec<Thumbnail>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommunityPlaylist {
+    pub browse_id:  String,
+    pub title:      String,
+    /// Who made the playlist.
+    pub artists:    Vec<MusicLink>,
+    pub view_count: Option<Count>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Episode {
+    pub video_id:   String,
+    pub title:      String,
+    /// The podcast the episode is from.
+    pub album:      Option<MusicLink>,
+    /// The podcast's hosts or channel.
+    pub artists:    Vec<MusicLink>,
+    /// The publish date as shown (e.g. "Jan 5, 2023" or "3 days ago").
+    pub published:  Option<String>,
+    pub year:       Option<u32>,
+    pub duration:   Option<Duration>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+impl MusicSearchResults {
+    pub(crate) async fn search(client: &Client, query: &str, filter: MusicFilter) -> Result<Self, Error> {
+        let response = endpoints::music_search(client, query, filter.params()).await?;
+        Self::from_response(&response, filter, &client.locale.hl)
+    }
+
+    /// `hl` is the language the response is in.
+    fn from_response(response: &Value, filter: MusicFilter, hl: &str) -> Result<Self, Error> {
+        let sections = response["contents"]["tabbedSearchResultsRenderer"]["tabs"]
+            .as_array()
+            .and_then(|tabs| {
+                tabs.iter()
+                    .find(|x| x["tabRenderer"]["selected"].as_bool() == Some(true))
+                    .or_else(|| tabs.first())
+            })
+            .and_then(|tab| tab["tabRenderer"]["content"]["sectionListRenderer"]["contents"].as_array())
+            .ok_or(Error::JsonParse("No search results found".into()))?;
+
+        let mut results = Self::default();
+        for section in sections {
+            if let Some(card) = section.get("musicCardShelfRenderer") {
+                results.top_result = MusicSearchItem::parse_card(card, filter, hl);
+            } else if let Some(items) = section["musicShelfRenderer"]["contents"].as_array() {
+                results.items.extend(
+                    items
+                        .iter()
+                        .filter_map(|x| x.get("musicResponsiveListItemRenderer"))
+                        .filter_map(|x| MusicSearchItem::parse(x, filter, hl)),
+                );
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// What to build a queue from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueueSource {
+    /// Specific songs or videos, the queue keeps their order.
+    Videos(Vec<String>),
+    /// Every track in a playlist or album (by its playlist ID, e.g. "OLAK5uy_...").
+    Playlist(String),
+}
+
+/// The tracks of a YouTube Music queue, in order.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MusicQueue {
+    pub tracks:       Vec<QueueTrack>,
+    /// Tracks that were left out because they couldn't be parsed. Always empty when
+    /// parsing with [`ParseMode::Strict`].
+    pub parse_errors: Vec<ParseError>,
+}
+
+/// A track in a YouTube Music queue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueTrack {
+    pub video_id:   String,
+    pub title:      String,
+    pub artists:    Vec<MusicLink>,
+    pub album:      Option<MusicLink>,
+    pub duration:   Option<Duration>,
+    pub year:       Option<u32>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+impl MusicQueue {
+    pub(crate) async fn get(client: &Client, source: &QueueSource) -> Result<Self, Error> {
+        let (video_ids, playlist_id) = match source {
+            QueueSource::Videos(x) => (Some(x.as_slice()), None),
+            QueueSource::Playlist(x) => (None, Some(x.as_str())),
+        };
+
+        let response = endpoints::music_get_queue(client, video_ids, playlist_id).await?;
+        Self::from_response(&response, client.parse_mode)
+    }
+
+    /// Parse a `get_queue` response. Tracks that fail to parse are dealt with according
+    /// to `parse_mode`.
+    fn from_response(response: &Value, parse_mode: ParseMode) -> Result<Self, Error> {
+        let queue = response["queueDatas"]
+            .as_array()
+            .ok_or(Error::JsonParse("No 'queueDatas' found".into()))?;
+        let mut result = Self::default();
+
+        for (i, item) in queue.iter().enumerate() {
+            let content = &item["content"];
+            // Songs with a music video come wrapped with the video as a counterpart.
+            let (path, track) = match content.get("playlistPanelVideoRenderer") {
+                Some(x) => ("playlistPanelVideoRenderer", x),
+                None => {
+                    match content["playlistPanelVideoWrapperRenderer"]["primaryRenderer"]
+                        .get("playlistPanelVideoRenderer")
+                    {
+                        Some(x) => (
+                            "playlistPanelVideoWrapperRenderer.primaryRenderer.playlistPanelVideoRenderer",
+                            x,
+                        ),
+                        None => continue,
+                    }
+                }
+            };
+
+            let error = match QueueTrack::parse(track) {
+                Ok(track) => {
+                    result.tracks.push(track);
+                    continue;
+                }
+                Err(e) => {
+                    ParseError {
+                        path:    format!("$.queueDatas[{i}].content.{path}"),
+                        value:   track.clone(),
+                        message: match e {
+                            Error::JsonParse(x) => x,
+                            e => e.to_string(),
+                        },
+                    }
+                }
+            };
+
+            match parse_mode {
+                ParseMode::Strict => return Err(Error::ItemParse(Box::new(error))),
+                ParseMode::Lenient => result.parse_errors.push(error),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl QueueTrack {
+    /// Parse a `playlistPanelVideoRenderer`.
+    fn parse(track: &Value) -> Result<Self, Error> {
+        let video_id = track["videoId"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or(Error::JsonParse("No video id found".into()))?;
+        let title = parse_text(&track["title"]).ok_or(Error::JsonParse("No title found".into()))?;
+
+        let byline = track["longBylineText"]["runs"]
+            .as_array()
+            .map(|x| x.iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let Subtitle {
+            artists,
+            album,
+            year,
+            duration,
+            ..
+        } = Subtitle::parse(&byline);
+
+        let thumbnails = track["thumbnail"]
+            .get("thumbnails")
+            .and_then(|x| serde_json::from_value(x.clone()).ok())
+            .unwrap_or_default();
+
+        Ok(Self {
+            video_id,
+            title,
+            artists,
+            album,
+            duration: parse_text(&track["lengthText"])
+                .and_then(|x| parse_clock_duration(&x))
+                .or(duration),
+            year,
+            thumbnails,
+        })
+    }
+}
+
+/// What a result is, as told by where it links to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Song,
+    MusicVideo,
+    Album,
+    Artist,
+    CommunityPlaylist,
+    Episode,
+}
+
+impl Kind {
+    fn from_page_type(page_type: &str) -> Option<Self> {
+        Some(match page_type {
+            "MUSIC_PAGE_TYPE_ALBUM" | "MUSIC_PAGE_TYPE_AUDIOBOOK" => Self::Album,
+            "MUSIC_PAGE_TYPE_ARTIST" | "MUSIC_PAGE_TYPE_USER_CHANNEL" => Self::Artist,
+            "MUSIC_PAGE_TYPE_PLAYLIST" => Self::CommunityPlaylist,
+            "MUSIC_PAGE_TYPE_NON_MUSIC_AUDIO_TRACK_PAGE" => Self::Episode,
+            _ => return None,
+        })
+    }
+
+    fn from_video_type(video_type: &str) -> Option<Self> {
+        Some(match video_type {
+            "MUSIC_VIDEO_TYPE_ATV" => Self::Song,
+            "MUSIC_VIDEO_TYPE_OMV" | "MUSIC_VIDEO_TYPE_UGC" | "MUSIC_VIDEO_TYPE_OFFICIAL_SOURCE_MUSIC" => {
+                Self::MusicVideo
+            }
+            "MUSIC_VIDEO_TYPE_PODCAST_EPISODE" => Self::Episode,
+            _ => return None,
+        })
+    }
+
+    fn from_filter(filter: MusicFilter) -> Option<Self> {
+        Some(match filter {
+            MusicFilter::All => return None,
+            MusicFilter::Songs => Self::Song,
+            MusicFilter::Videos => Self::MusicVideo,
+            MusicFilter::Albums => Self::Album,
+            MusicFilter::Artists => Self::Artist,
+            MusicFilter::CommunityPlaylists | MusicFilter::FeaturedPlaylists => Self::CommunityPlaylist,
+            MusicFilter::Episodes => Self::Episode,
+        })
+    }
+}
+
+/// The parts of a result every layout has.
+struct Parts<'a> {
+    title:      Option<String>,
+    /// The runs of the text under the title.
+    subtitle:   Vec<&'a Value>,
+    browse:     Option<&'a Value>,
+    watch:      Option<&'a Value>,
+    thumbnails: Vec<Thumbnail>,
+    explicit:   bool,
+}
+
+impl MusicSearchItem {
+    /// Parse a `musicResponsiveListItemRenderer`. Returns `None` for kinds of results
+    /// that aren't supported (e.g. podcasts and profiles).
+    fn parse(item: &Value, filter: MusicFilter, hl: &str) -> Option<Self> {
+        let column = |i: usize| &item["flexColumns"][i]["musicResponsiveListItemFlexColumnRenderer"]["text"];
+
+        // The columns after the title are shown joined by dots.
+        let mut subtitle = Vec::new();
+        for column in item["flexColumns"].as_array()?.iter().skip(1) {
+            let Some(runs) = column["musicResponsiveListItemFlexColumnRenderer"]["text"]["runs"].as_array()
+            else {
+                continue;
+            };
+            if !subtitle.is_empty() {
+                subtitle.push(&SEPARATOR);
+            }
+            subtitle.extend(runs);
+        }
+
+        let watch = item["overlay"]["musicItemThumbnailOverlayRenderer"]["content"]
+            ["musicPlayButtonRenderer"]["playNavigationEndpoint"]
+            .get("watchEndpoint")
+            .or_else(|| column(0)["runs"][0]["navigationEndpoint"].get("watchEndpoint"))
+            .or_else(|| {
+                item["playlistItemData"]
+                    .get("videoId")
+                    .map(|_| &item["playlistItemData"])
+            });
+
+        let parts = Parts {
+            title: parse_text(column(0)),
+            subtitle,
+            browse: item["navigationEndpoint"].get("browseEndpoint"),
+            watch,
+            thumbnails: parse_thumbnails(&item["thumbnail"]),
+            explicit: is_explicit(&item["badges"]),
+        };
+
+        Self::from_parts(parts, filter, hl)
+    }
+
+    /// Parse the `musicCardShelfRenderer` the top result is shown in.
+    fn parse_card(card: &Value, filter: MusicFilter, hl: &str) -> Option<Self> {
+        let title_endpoint = &card["title"]["runs"][0]["navigationEndpoint"];
+        let parts = Parts {
+            title:      parse_text(&card["title"]),
+            subtitle:   card["subtitle"]["runs"].as_array()?.iter().collect(),
+            browse:     title_endpoint.get("browseEndpoint"),
+            watch:      title_endpoint.get("watchEndpoint"),
+            thumbnails: parse_thumbnails(&card["thumbnail"]),
+            explicit:   is_explicit(&card["subtitleBadges"]),
+        };
+
+        Self::from_parts(parts, filter, hl)
+    }
+
+    fn from_parts(parts: Parts, filter: MusicFilter, hl: &str) -> Option<Self> {
+        let browse_id = parts
+            .browse
+            .and_then(|x| x["browseId"].as_str())
+            .map(str::to_string);
+        let video_id = parts
+            .watch
+            .and_then(|x| x["videoId"].as_str())
+            .map(str::to_string);
+
+        let subtitle = Subtitle::parse(&parts.subtitle);
+        let kind = parts
+            .browse
+            .and_then(|x| x.pointer(PAGE_TYPE_POINTER)?.as_str())
+            .and_then(Kind::from_page_type)
+            .or_else(|| {
+                parts
+                    .watch
+                    .and_then(|x| x.pointer(VIDEO_TYPE_POINTER)?.as_str())
+                    .and_then(Kind::from_video_type)
+            })
+            .or_else(|| Kind::from_filter(filter))
+            .or(subtitle.kind)?;
+
+        let title = parts.title?;
+        let thumbnails = parts.thumbnails;
+        let Subtitle {
+            label,
+            artists,
+            album,
+            duration,
+            year,
+            count,
+            other,
+            ..
+        } = subtitle;
+
+        Some(match kind {
+            Kind::Song => {
+                Self::Song(Song {
+                    video_id: video_id?,
+                    title,
+                    artists,
+                    album,
+                    duration,
+                    year,
+                    explicit: parts.explicit,
+                    thumbnails,
+                })
+            }
+            Kind::MusicVideo => {
+                Self::MusicVideo(MusicVideo {
+                    video_id: video_id?,
+                    title,
+                    artists,
+                    album,
+                    duration,
+                    year,
+                    view_count: count.and_then(|x| parse_count(&x, hl).ok()),
+                    thumbnails,
+                })
+            }
+            Kind::Album => {
+                Self::Album(Album {
+                    browse_id: browse_id?,
+                    title,
+                    album_type: label,
+                    artists,
+                    year,
+                    duration,
+                    explicit: parts.explicit,
+                    thumbnails,
+                })
+            }
+            Kind::Artist => {
+                Self::Artist(Artist {
+                    browse_id: browse_id?,
+                    name: title,
+                    audience: count.as_deref().and_then(|x| parse_count(x, hl).ok()),
+                    audience_text: count,
+                    thumbnails,
+                })
+            }
+            Kind::CommunityPlaylist => {
+                Self::CommunityPlaylist(CommunityPlaylist {
+                    browse_id: browse_id?,
+                    title,
+                    artists,
+                    view_count: count.and_then(|x| parse_count(&x, hl).ok()),
+                    thumbnails,
+                })
+            }
+            Kind::Episode => {
+                Self::Episode(Episode {
+                    video_id: video_id?,
+                    title,
+                    album,
+                    artists,
+                    year,
+                    // Relative dates (e.g. "3 days ago") look a lot like counts.
+                    published: other.into_iter().next().or(count),
+                    duration,
+                    thumbnails,
+                })
+            }
+        })
+    }
+}
+
+const PAGE_TYPE_POINTER: &str =
+    "/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType";
+const VIDEO_TYPE_POINTER: &str =
+    "/watchEndpointSupportedConfigs/watchEndpointMusicSupportedConfigs/watchEndpointMusicConfig/musicVideoType";
+/// Podcasts aren't results of their own, but episodes link to theirs the way songs link
+/// to their album.
+const PODCAST_PAGE_TYPE: &str = "MUSIC_PAGE_TYPE_PODCAST_SHOW_PAGE";
+
+/// Stands in for the dots between columns.
+static SEPARATOR: Value = Value::Null;
+
+/// The text under a result's title, e.g. "Song • Rick Astley • Whenever You Need Somebody
+/// • 3:34".
+#[derive(Debug, Default)]
+struct Subtitle {
+    /// What the result is, only shown in the "All" tab.
+    kind:     Option<Kind>,
+    /// The text `kind` was worked out from (e.g. "Single").
+    label:    Option<String>,
+    artists:  Vec<MusicLink>,
+    album:    Option<MusicLink>,
+    duration: Option<Duration>,
+    year:     Option<u32>,
+    /// A view, play, or subscriber count.
+    count:    Option<String>,
+    /// Anything that couldn't be worked out.
+    other:    Vec<String>,
+}
+
+impl Subtitle {
+    fn parse(runs: &[&Value]) -> Self {
+        let mut subtitle = Self::default();
+
+        let groups = runs.split(|run| run.is_null() || run["text"].as_str().is_some_and(|x| x.trim() == "•"));
+        for (i, group) in groups.enumerate() {
+            let text = group
+                .iter()
+                .filter_map(|x| x["text"].as_str())
+                .collect::<String>();
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            let links = group
+                .iter()
+                .filter_map(|run| {
+                    let endpoint = run["navigationEndpoint"].get("browseEndpoint")?;
+                    let page_type = endpoint.pointer(PAGE_TYPE_POINTER).and_then(Value::as_str);
+                    let link = MusicLink {
+                        name: run["text"].as_str()?.to_string(),
+                        id:   endpoint["browseId"].as_str().map(str::to_string),
+                    };
+                    let is_album = page_type.is_some_and(|x| {
+                        x == PODCAST_PAGE_TYPE || Kind::from_page_type(x) == Some(Kind::Album)
+                    });
+                    Some((is_album, link))
+                })
+                .collect::<Vec<_>>();
+
+            if !links.is_empty() {
+                for (is_album, link) in links {
+                    if is_album {
+                        subtitle.album = Some(link);
+                    } else {
+                        subtitle.artists.push(link);
+                    }
+                }
+            } else if i == 0 && subtitle.label.is_none() && Self::parse_label(text).is_some() {
+                subtitle.kind = Self::parse_label(text);
+                subtitle.label = Some(text.to_string());
+            } else if let Some(duration) = parse_clock_duration(text) {
+                subtitle.duration = Some(duration);
+            } else if text.len() == 4 && text.bytes().all(|x| x.is_ascii_digit()) {
+                subtitle.year = text.parse().ok();
+            } else if text.starts_with(|ch: char| ch.is_ascii_digit())
+                && text.ends_with(|ch: char| ch.is_alphabetic())
+                && text.contains(' ')
+            {
+                subtitle.count = Some(text.to_string());
+            } else if let Some(year) = trailing_year(text) {
+                // Dates like "Jan 5, 2023".
+                subtitle.year = subtitle.year.or(Some(year));
+                subtitle.other.push(text.to_string());
+            } else if subtitle.artists.is_empty() && subtitle.other.is_empty() {
+                // Artists without a page of their own aren't links.
+                subtitle.artists.push(MusicLink {
+                    name: text.to_string(),
+                    id:   None,
+                });
+            } else {
+                subtitle.other.push(text.to_string());
+            }
+        }
+
+        subtitle
+    }
+
+    /// The kind of result from the label in front of it in the "All" tab.
+    fn parse_label(text: &str) -> Option<Kind> {
+        Some(match text {
+            "Song" => Kind::Song,
+            "Video" => Kind::MusicVideo,
+            "Album" | "Single" | "EP" | "Audiobook" => Kind::Album,
+            "Artist" => Kind::Artist,
+            "Playlist" => Kind::CommunityPlaylist,
+            "Episode" => Kind::Episode,
+            _ => return None,
+        })
+    }
+}
+
+fn trailing_year(text: &str) -> Option<u32> {
+    let (_, year) = text.rsplit_once(' ')?;
+    if year.len() == 4 && year.bytes().all(|x| x.is_ascii_digit()) {
+        year.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn parse_thumbnails(thumbnail: &Value) -> Vec<Thumbnail> {
+    thumbnail["musicThumbnailRenderer"]["thumbnail"]
+        .get("thumbnails")
+        .and_then(|x| serde_json::from_value(x.clone()).ok())
+        .unwrap_or_default()
+}
+
+fn is_explicit(badges: &Value) -> bool {
+    badges.as_array().is_some_and(|badges| {
+        badges.iter().any(|x| {
+            x["musicInlineBadgeRenderer"]["icon"]["iconType"].as_str() == Some("MUSIC_EXPLICIT_BADGE")
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn column(runs: Value) -> Value {
+        json!({ "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": runs } } })
+    }
+
+    fn link(text: &str, id: &str, page_type: &str) -> Value {
+        json!({ "text": text, "navigationEndpoint": { "browseEndpoint": {
+            "browseId": id,
+            "browseEndpointContextSupportedConfigs": { "browseEndpointContextMusicConfig": { "pageType": page_type } }
+        } } })
+    }
+
+    fn watch(video_id: &str, video_type: &str) -> Value {
+        json!({ "musicItemThumbnailOverlayRenderer": { "content": { "musicPlayButtonRenderer": {
+            "playNavigationEndpoint": { "watchEndpoint": {
+                "videoId": video_id,
+                "watchEndpointSupportedConfigs": { "watchEndpointMusicSupportedConfigs": {
+                    "watchEndpointMusicConfig": { "musicVideoType": video_type }
+                } }
+            } }
+        } } } })
+    }
+
+    fn browse(id: &str, page_type: &str) -> Value { link("", id, page_type)["navigationEndpoint"].clone() }
+
+    fn response(shelves: Value) -> Value {
+        json!({ "contents": { "tabbedSearchResultsRenderer": { "tabs": [{ "tabRenderer": {
+            "selected": true,
+            "content": { "sectionListRenderer": { "contents": shelves } }
+        } }] } } })
+    }
+
+    fn rick_astley() -> Value {
+        link(
+            "Rick Astley",
+            "UCuAXFkgsw1L7xaCfnd5JJOw",
+            "MUSIC_PAGE_TYPE_ARTIST",
+        )
+    }
+
+    #[test]
+    fn test_parse_all_tab() {
+        let dot = json!({ "text": " • " });
+        let response = response(json!([
+            { "musicCardShelfRenderer": {
+                "title": { "runs": [{
+                    "text": "Never Gonna Give You Up",
+                    "navigationEndpoint": watch("lYBUbBu4W08", "MUSIC_VIDEO_TYPE_ATV")
+                        ["musicItemThumbnailOverlayRenderer"]["content"]["musicPlayButtonRenderer"]["playNavigationEndpoint"].clone()
+                }] },
+                "subtitle": { "runs": [{ "text": "Song" }, dot, rick_astley(), dot, { "text": "3:34" }] }
+            } },
+            { "musicShelfRenderer": { "contents": [
+                { "musicResponsiveListItemRenderer": {
+                    "flexColumns": [
+                        column(json!([{ "text": "Never Gonna Give You Up" }])),
+                        column(json!([
+                            { "text": "Video" }, dot, rick_astley(), dot, { "text": "1.6B views" }, dot, { "text": "3:33" }
+                        ]))
+                    ],
+                    "overlay": watch("dQw4w9WgXcQ", "MUSIC_VIDEO_TYPE_OMV")
+                } },
+                { "musicResponsiveListItemRenderer": {
+                    "flexColumns": [
+                        column(json!([{ "text": "Whenever You Need Somebody" }])),
+                        column(json!([{ "text": "Album" }, dot, rick_astley(), dot, { "text": "1987" }]))
+                    ],
+                    "badges": [{ "musicInlineBadgeRenderer": { "icon": { "iconType": "MUSIC_EXPLICIT_BADGE" } } }],
+                    "navigationEndpoint": browse("MPREb_qQJJe9G8rO5", "MUSIC_PAGE_TYPE_ALBUM")
+                } },
+                { "musicResponsiveListItemRenderer": {
+                    "thumbnail": { "musicThumbnailRenderer": { "thumbnail": { "thumbnails": [
+                        { "url": "https://lh3.googleusercontent.com/a", "width": 60, "height": 60 }
+                    ] } } },
+                    "flexColumns": [
+                        column(json!([{ "text": "Rick Astley" }])),
+                        column(json!([{ "text": "Artist" }, dot, { "text": "4.5M monthly audience" }]))
+                    ],
+                    "navigationEndpoint": browse("UCuAXFkgsw1L7xaCfnd5JJOw", "MUSIC_PAGE_TYPE_ARTIST")
+                } },
+                { "musicResponsiveListItemRenderer": {
+                    "flexColumns": [
+                        column(json!([{ "text": "80s Hits" }])),
+                        column(json!([{ "text": "Playlist" }, dot, { "text": "Someone" }, dot, { "text": "12K views" }]))
+                    ],
+                    "navigationEndpoint": browse("VLPLxxxxxxxx", "MUSIC_PAGE_TYPE_PLAYLIST")
+                } },
+                { "musicResponsiveListItemRenderer": {
+                    "flexColumns": [
+                        column(json!([{ "text": "Episode 12: Rickrolling" }])),
+                        column(json!([
+                            { "text": "Episode" }, dot, { "text": "Jan 5, 2023" }, dot,
+                            link("Internet History", "MPSPPLxxxxxxxx", "MUSIC_PAGE_TYPE_PODCAST_SHOW_PAGE")
+                        ]))
+                    ],
+                    "overlay": watch("eeeeeeeeeee", "MUSIC_VIDEO_TYPE_PODCAST_EPISODE")
+                } },
+                { "musicResponsiveListItemRenderer": {
+                    "flexColumns": [column(json!([{ "text": "Some Podcast" }]))],
+                    "navigationEndpoint": browse("MPSPPLxxxxxxxx", "MUSIC_PAGE_TYPE_PODCAST_SHOW_PAGE")
+                } }
+            ] } }
+        ]));
+
+        let results = MusicSearchResults::from_response(&response, MusicFilter::All, "en").unwrap();
+        let Some(MusicSearchItem::Song(top)) = &results.top_result else {
+            panic!("{:?}", results.top_result);
+        };
+        assert_eq!(top.video_id, "lYBUbBu4W08");
+        assert_eq!(top.artists[0].name, "Rick Astley");
+        assert_eq!(top.duration, Some(Duration::from_secs(214)));
+
+        // Podcasts aren't supported, so they're left out.
+        assert_eq!(results.items.len(), 5);
+        let MusicSearchItem::MusicVideo(video) = &results.items[0] else {
+            panic!()
+        };
+        assert_eq!(video.video_id, "dQw4w9WgXcQ");
+        assert_eq!(video.view_count.map(|x| x.value), Some(1_600_000_000));
+        assert_eq!(video.duration, Some(Duration::from_secs(213)));
+
+        let MusicSearchItem::Album(album) = &results.items[1] else {
+            panic!()
+        };
+        assert_eq!(album.browse_id, "MPREb_qQJJe9G8rO5");
+        assert_eq!(album.album_type.as_deref(), Some("Album"));
+        assert_eq!(album.artists[0].id.as_deref(), Some("UCuAXFkgsw1L7xaCfnd5JJOw"));
+        assert_eq!(album.year, Some(1987));
+        assert!(album.explicit);
+
+        let MusicSearchItem::Artist(artist) = &results.items[2] else {
+            panic!()
+        };
+        assert_eq!(artist.name, "Rick Astley");
+        assert_eq!(artist.audience.map(|x| x.value), Some(4_500_000));
+        assert_eq!(artist.audience_text.as_deref(), Some("4.5M monthly audience"));
+        assert_eq!(artist.thumbnails.len(), 1);
+
+        let MusicSearchItem::CommunityPlaylist(playlist) = &results.items[3] else {
+            panic!()
+        };
+        assert_eq!(
+            playlist.artists,
+            [MusicLink {
+                name: "Someone".into(),
+                id:   None,
+            }]
+        );
+        assert_eq!(playlist.view_count.map(|x| x.value), Some(12_000));
+
+        let MusicSearchItem::Episode(episode) = &results.items[4] else {
+            panic!()
+        };
+        assert_eq!(episode.video_id, "eeeeeeeeeee");
+        assert_eq!(episode.published.as_deref(), Some("Jan 5, 2023"));
+        assert_eq!(episode.year, Some(2023));
+        assert_eq!(
+            episode.album,
+            Some(MusicLink {
+                name: "Internet History".into(),
+                id:   Some("MPSPPLxxxxxxxx".into()),
+            })
+        );
+        assert!(episode.artists.is_empty());
+    }
+
+    #[test]
+    fn test_parse_queue() {
+        let dot = json!({ "text": " • " });
+        let track = |video_id: &str| {
+            json!({
+                "videoId": video_id,
+                "title": { "runs": [{ "text": "Never Gonna Give You Up" }] },
+                "longBylineText": { "runs": [
+                    rick_astley(), dot,
+                    link("Whenever You Need Somebody", "MPREb_qQJJe9G8rO5", "MUSIC_PAGE_TYPE_ALBUM"), dot,
+                    { "text": "1987" }
+                ] },
+                "lengthText": { "runs": [{ "text": "3:34" }] },
+                "thumbnail": { "thumbnails": [{ "url": "https://i.ytimg.com/vi/lYBUbBu4W08/sddefault.jpg" }] }
+            })
+        };
+        let response = json!({ "queueDatas": [
+            { "content": { "playlistPanelVideoRenderer": track("lYBUbBu4W08") } },
+            { "content": { "playlistPanelVideoWrapperRenderer": {
+                "primaryRenderer": { "playlistPanelVideoRenderer": track("yPYZpwSpKmA") },
+                "counterpart": [{ "counterpartRenderer": { "playlistPanelVideoRenderer": track("dQw4w9WgXcQ") } }]
+            } } }
+        ] });
+
+        let queue = MusicQueue::from_response(&response, ParseMode::Strict)
+            .unwrap()
+            .tracks;
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue[0].video_id, "lYBUbBu4W08");
+        assert_eq!(queue[0].title, "Never Gonna Give You Up");
+        assert_eq!(queue[0].artists[0].name, "Rick Astley");
+        assert_eq!(
+            queue[0].album.as_ref().unwrap().id.as_deref(),
+            Some("MPREb_qQJJe9G8rO5")
+        );
+        assert_eq!(queue[0].year, Some(1987));
+        assert_eq!(queue[0].duration, Some(Duration::from_secs(214)));
+        assert_eq!(queue[0].thumbnails.len(), 1);
+        assert_eq!(queue[1].video_id, "yPYZpwSpKmA");
+    }
+
+    #[test]
+    fn test_parse_queue_malformed_track() {
+        let response = json!({ "queueDatas": [
+            { "content": { "playlistPanelVideoRenderer": { "title": { "runs": [{ "text": "No video id" }] } } } },
+            { "content": { "playlistPanelVideoRenderer": {
+                "videoId": "lYBUbBu4W08",
+                "title": { "runs": [{ "text": "Never Gonna Give You Up" }] }
+            } } }
+        ] });
+
+        let queue = MusicQueue::from_response(&response, ParseMode::Lenient).unwrap();
+        assert_eq!(queue.tracks.len(), 1);
+        assert_eq!(queue.tracks[0].video_id, "lYBUbBu4W08");
+        assert_eq!(queue.parse_errors.len(), 1);
+        assert_eq!(
+            queue.parse_errors[0].path,
+            "$.queueDatas[0].content.playlistPanelVideoRenderer"
+        );
+        assert_eq!(queue.parse_errors[0].message, "No video id found");
+
+        let Err(Error::ItemParse(error)) = MusicQueue::from_response(&response, ParseMode::Strict) else {
+            panic!("expected the malformed track to fail in strict mode");
+        };
+        assert_eq!(error.path, "$.queueDatas[0].content.playlistPanelVideoRenderer");
+    }
+
+    #[test]
+    fn test_parse_songs_tab() {
+        let dot = json!({ "text": " • " });
+        let response = response(json!([{ "musicShelfRenderer": { "contents": [
+            { "musicResponsiveListItemRenderer": {
+                "flexColumns": [
+                    column(json!([{ "text": "Never Gonna Give You Up" }])),
+                    column(json!([
+                        rick_astley(), dot,
+                        link("Whenever You Need Somebody", "MPREb_qQJJe9G8rO5", "MUSIC_PAGE_TYPE_ALBUM"), dot,
+                        { "text": "3:34" }
+                    ]))
+                ],
+                // No video type here, so the filter decides.
+                "playlistItemData": { "videoId": "lYBUbBu4W08" }
+            } }
+        ] } }]));
+
+        let results = MusicSearchResults::from_response(&response, MusicFilter::Songs, "en").unwrap();
+        let [MusicSearchItem::Song(song)] = &results.items[..] else {
+            panic!("{:?}", results.items);
+        };
+        assert_eq!(song.video_id, "lYBUbBu4W08");
+        assert_eq!(song.title, "Never Gonna Give You Up");
+        assert_eq!(song.album.as_ref().unwrap().name, "Whenever You Need Somebody");
+        assert_eq!(
+            song.album.as_ref().unwrap().id.as_deref(),
+            Some("MPREb_qQJJe9G8rO5")
+        );
+        assert_eq!(song.artists.len(), 1);
+        assert_eq!(song.duration, Some(Duration::from_secs(214)));
+        assert!(!song.explicit);
+    }
+}
@@ -0,0 +1,153 @@
+//! Just enough of the protobuf wire format to build the handful of messages `YouTube`
+//! expects as base64 encoded parameters.
+
+/// Wire type of a varint field.
+pub(crate) const WIRE_VARINT: u8 = 0;
+/// Wire type of a length-delimited field (strings, bytes, and nested messages).
+pub(crate) const WIRE_LEN: u8 = 2;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    pub(crate) fn new() -> Self { Self::default() }
+
+    pub(crate) fn is_empty(&self) -> bool { self.buf.is_empty() }
+
+    pub(crate) fn into_bytes(self) -> Vec<u8> { self.buf }
+
+    /// Write a varint field.
+    pub(crate) fn varint(&mut self, field: u32, value: u64) -> &mut Self {
+        self.key(field, WIRE_VARINT);
+        self.raw_varint(value);
+        self
+    }
+
+    /// Write a length-delimited field, this is used for both bytes and nested messages.
+    pub(crate) fn bytes(&mut self, field: u32, value: &[u8]) -> &mut Self {
+        self.key(field, WIRE_LEN);
+        self.raw_varint(value.len() as u64);
+        self.buf.extend_from_slice(value);
+        self
+    }
+
+    fn key(&mut self, field: u32, wire_type: u8) {
+        self.raw_varint(u64::from(field) << 3 | u64::from(wire_type));
+    }
+
+    fn raw_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push((value as u8 & 0x7F) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+}
+
+/// A decoded field value. Only the wire types `YouTube` actually uses are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Field<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+}
+
+/// Iterates over the `(field number, value)` pairs of a message.
+#[derive(Debug, Clone)]
+pub(crate) struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self { Self { buf } }
+
+    fn raw_varint(&mut self) -> Result<u64, String> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let (&byte, rest) = self.buf.split_first().ok_or("Truncated varint")?;
+            self.buf = rest;
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("Varint is too long".to_string())
+    }
+
+    fn field(&mut self) -> Result<(u32, Field<'a>), String> {
+        let key = self.raw_varint()?;
+        let field = u32::try_from(key >> 3).map_err(|_| format!("Invalid field number {}", key >> 3))?;
+        let value = match (key & 0x7) as u8 {
+            WIRE_VARINT => Field::Varint(self.raw_varint()?),
+            WIRE_LEN => {
+                let len = usize::try_from(self.raw_varint()?).map_err(|e| e.to_string())?;
+                if len > self.buf.len() {
+                    return Err(format!("Field {field} is truncated"));
+                }
+                let (bytes, rest) = self.buf.split_at(len);
+                self.buf = rest;
+                Field::Bytes(bytes)
+            }
+            wire_type => return Err(format!("Field {field} has unsupported wire type {wire_type}")),
+        };
+
+        Ok((field, value))
+    }
+}
+
+impl<'a> Iterator for Reader<'a> {
+    type Item = Result<(u32, Field<'a>), String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+
+        let field = self.field();
+        if field.is_err() {
+            // Don't keep reading garbage after an error.
+            self.buf = &[];
+        }
+        Some(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_varint() {
+        let mut writer = Writer::new();
+        writer.varint(1, 150);
+        assert_eq!(writer.into_bytes(), [0x08, 0x96, 0x01]);
+    }
+
+    #[test]
+    fn test_write_nested() {
+        let mut inner = Writer::new();
+        inner.varint(2, 1).varint(3, 3);
+        let mut outer = Writer::new();
+        outer.bytes(2, &inner.into_bytes());
+        assert_eq!(outer.into_bytes(), [0x12, 0x04, 0x10, 0x01, 0x18, 0x03]);
+    }
+
+    #[test]
+    fn test_read_nested() {
+        let fields = Reader::new(&[0x08, 0x96, 0x01, 0x12, 0x02, 0x10, 0x01])
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            fields,
+            [(1, Field::Varint(150)), (2, Field::Bytes(&[0x10, 0x01]))]
+        );
+    }
+
+    #[test]
+    fn test_read_truncated() {
+        let mut reader = Reader::new(&[0x12, 0x04, 0x10]);
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,355 @@
+//! Parsing the relative publish times `YouTube` shows (e.g. "3 weeks ago", "Streamed 2
+//! days ago") in whatever language the client asked for.
+//!
+//! `YouTube` only ever shows the largest whole unit and rounds down, so "3 weeks ago"
+//! could be anything from 3 to 4 weeks ago. The time is given as the middle of that
+//! window.
+
+use std::{
+    ops::Range,
+    time::{Duration, SystemTime},
+};
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+const WEEK: u64 = 7 * DAY;
+/// The average length of a month and year in the Gregorian calendar.
+const MONTH: u64 = 2_629_746;
+const YEAR: u64 = 31_556_952;
+
+/// When something was published, worked out from relative text like "3 weeks ago".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PublishedTime {
+    pub kind:        PublishKind,
+    /// The middle of the window the real time is in.
+    pub timestamp:   SystemTime,
+    /// How far the real time could be from [`PublishedTime::timestamp`], either way.
+    pub uncertainty: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PublishKind {
+    /// A regular upload.
+    #[default]
+    Uploaded,
+    /// A live stream that has ended, the time is when it was streamed.
+    Streamed,
+    /// A premiere, the time is when it premiered.
+    Premiered,
+}
+
+impl PublishedTime {
+    /// Parse text like "3 weeks ago" written in the language `hl`, relative to `now`.
+    /// Returns `None` for anything that isn't a relative time (e.g. "Jan 5, 2023").
+    #[must_use]
+    pub fn parse(text: &str, hl: &str, now: SystemTime) -> Option<Self> {
+        let language = language(hl);
+        let text = text.to_lowercase();
+
+        let start = text.find(|ch: char| ch.is_ascii_digit())?;
+        let number_len = text[start..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(text.len() - start);
+        let amount = text[start..start + number_len].parse::<u64>().ok()?;
+
+        let rest = text[start + number_len..].trim_start();
+        let unit = language
+            .units
+            .iter()
+            .find(|(word, _)| rest.starts_with(word))
+            .map(|(_, unit)| *unit)?;
+
+        // Premieres are checked first as some languages call them a "first stream".
+        let kind = if language.premiered.iter().any(|x| text.contains(x)) {
+            PublishKind::Premiered
+        } else if language.streamed.iter().any(|x| text.contains(x)) {
+            PublishKind::Streamed
+        } else {
+            PublishKind::Uploaded
+        };
+
+        let uncertainty = Duration::from_secs(unit / 2);
+        let ago = Duration::from_secs(amount.checked_mul(unit)?).checked_add(uncertainty)?;
+        Some(Self {
+            kind,
+            timestamp: now.checked_sub(ago)?,
+            uncertainty,
+        })
+    }
+
+    /// The window this could have been published in, as a half-open range from its
+    /// earliest time up to (but not including) its latest. Returns `None` if either end
+    /// can't be represented as a `SystemTime`.
+    #[must_use]
+    pub fn range(&self) -> Option<Range<SystemTime>> {
+        Some(self.timestamp.checked_sub(self.uncertainty)?..self.timestamp.checked_add(self.uncertainty)?)
+    }
+}
+
+/// The words for each unit of time in the language `hl`, and how many seconds the unit
+/// is.
+pub(crate) fn time_units(hl: &str) -> &'static [(&'static str, u64)] { language(hl).units }
+
+struct Language {
+    codes:     &'static [&'static str],
+    /// The start of each unit's word, in every form it's written in (e.g. "week" for
+    /// "week" and "weeks"). Longer words that start with a shorter one go first.
+    units:     &'static [(&'static str, u64)],
+    streamed:  &'static [&'static str],
+    premiered: &'static [&'static str],
+}
+
+fn language(hl: &str) -> &'static Language {
+    let code = hl.split(['-', '_']).next().unwrap_or(hl).to_lowercase();
+    LANGUAGES
+        .iter()
+        .find(|x| x.codes.contains(&code.as_str()))
+        .unwrap_or(&LANGUAGES[0])
+}
+
+const LANGUAGES: &[Language] = &[
+    Language {
+        codes:     &["en"],
+        units:     &[
+            ("second", 1),
+            ("minute", MINUTE),
+            ("hour", HOUR),
+            ("day", DAY),
+            ("week", WEEK),
+            ("month", MONTH),
+            ("year", YEAR),
+        ],
+        streamed:  &["streamed"],
+        premiered: &["premiered"],
+    },
+    Language {
+        codes:     &["de"],
+        units:     &[
+            ("sekunde", 1),
+            ("minute", MINUTE),
+            ("stunde", HOUR),
+            ("tag", DAY),
+            ("woche", WEEK),
+            ("monat", MONTH),
+            ("jahr", YEAR),
+        ],
+        streamed:  &["live übertragen", "gestreamt"],
+        premiered: &["premiere"],
+    },
+    Language {
+        codes:     &["fr"],
+        units:     &[
+            ("seconde", 1),
+            ("minute", MINUTE),
+            ("heure", HOUR),
+            ("jour", DAY),
+            ("semaine", WEEK),
+            ("mois", MONTH),
+            ("an", YEAR),
+        ],
+        streamed:  &["diffusé"],
+        premiered: &["première"],
+    },
+    Language {
+        codes:     &["es"],
+        units:     &[
+            ("segundo", 1),
+            ("minuto", MINUTE),
+            ("hora", HOUR),
+            ("día", DAY),
+            ("dia", DAY),
+            ("semana", WEEK),
+            ("mes", MONTH),
+            ("año", YEAR),
+        ],
+        streamed:  &["emitido", "transmitido"],
+        premiered: &["estrenado", "estreno"],
+    },
+    Language {
+        codes:     &["pt"],
+        units:     &[
+            ("segundo", 1),
+            ("minuto", MINUTE),
+            ("hora", HOUR),
+            ("dia", DAY),
+            ("semana", WEEK),
+            ("mês", MONTH),
+            ("mes", MONTH),
+            ("ano", YEAR),
+        ],
+        streamed:  &["transmitido"],
+        premiered: &["estreou", "estreia"],
+    },
+    Language {
+        codes:     &["it"],
+        units:     &[
+            ("second", 1),
+            ("minut", MINUTE),
+            ("or", HOUR),
+            ("giorn", DAY),
+            ("settiman", WEEK),
+            ("mes", MONTH),
+            ("ann", YEAR),
+        ],
+        streamed:  &["trasmesso"],
+        premiered: &["anteprima"],
+    },
+    Language {
+        codes:     &["ru"],
+        units:     &[
+            ("секунд", 1),
+            ("минут", MINUTE),
+            ("час", HOUR),
+            ("день", DAY),
+            ("дн", DAY),
+            ("недел", WEEK),
+            ("месяц", MONTH),
+            ("год", YEAR),
+            ("лет", YEAR),
+        ],
+        streamed:  &["трансляция"],
+        premiered: &["премьера"],
+    },
+    Language {
+        codes:     &["ja"],
+        units:     &[
+            ("秒", 1),
+            ("分", MINUTE),
+            ("時間", HOUR),
+            ("日", DAY),
+            ("週間", WEEK),
+            ("か月", MONTH),
+            ("ヶ月", MONTH),
+            ("ヵ月", MONTH),
+            ("年", YEAR),
+        ],
+        streamed:  &["配信"],
+        premiered: &["プレミア"],
+    },
+    Language {
+        codes:     &["ko"],
+        units:     &[
+            ("초", 1),
+            ("분", MINUTE),
+            ("시간", HOUR),
+            ("일", DAY),
+            ("주", WEEK),
+            ("개월", MONTH),
+            ("년", YEAR),
+        ],
+        streamed:  &["스트리밍"],
+        premiered: &["최초 공개"],
+    },
+    Language {
+        codes:     &["zh"],
+        units:     &[
+            ("秒", 1),
+            ("分", MINUTE),
+            ("小时", HOUR),
+            ("小時", HOUR),
+            ("天", DAY),
+            ("周", WEEK),
+            ("週", WEEK),
+            ("个月", MONTH),
+            ("個月", MONTH),
+            ("年", YEAR),
+        ],
+        streamed:  &["直播"],
+        premiered: &["首播"],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str, hl: &str) -> Option<(PublishKind, u64, u64)> {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(100 * YEAR);
+        PublishedTime::parse(text, hl, now).map(|x| {
+            let ago = now.duration_since(x.timestamp).unwrap();
+            (x.kind, ago.as_secs(), x.uncertainty.as_secs())
+        })
+    }
+
+    #[test]
+    fn test_parse_published_english() {
+        assert_eq!(
+            parse("3 weeks ago", "en"),
+            Some((PublishKind::Uploaded, 3 * WEEK + WEEK / 2, WEEK / 2))
+        );
+        assert_eq!(
+            parse("1 day ago", "en-GB"),
+            Some((PublishKind::Uploaded, DAY + DAY / 2, DAY / 2))
+        );
+        assert_eq!(
+            parse("Streamed 2 days ago", "en"),
+            Some((PublishKind::Streamed, 2 * DAY + DAY / 2, DAY / 2))
+        );
+        assert_eq!(
+            parse("Premiered 5 hours ago", "en"),
+            Some((PublishKind::Premiered, 5 * HOUR + HOUR / 2, HOUR / 2))
+        );
+        assert_eq!(
+            parse("14 years ago", "en").map(|x| x.1),
+            Some(14 * YEAR + YEAR / 2)
+        );
+        assert_eq!(parse("Jan 5, 2023", "en"), None);
+
+        // Too long ago to fit in a `Duration`.
+        assert_eq!(parse("600000000000 years ago", "en"), None);
+        assert_eq!(parse("307445734561825860 minutes ago", "en"), None);
+    }
+
+    #[test]
+    fn test_parse_published_localized() {
+        assert_eq!(
+            parse("vor 3 Wochen", "de"),
+            Some((PublishKind::Uploaded, 3 * WEEK + WEEK / 2, WEEK / 2))
+        );
+        assert_eq!(
+            parse("Live übertragen vor 2 Tagen", "de").map(|x| x.0),
+            Some(PublishKind::Streamed)
+        );
+        assert_eq!(parse("il y a 1 an", "fr").map(|x| x.1), Some(YEAR + YEAR / 2));
+        assert_eq!(
+            parse("Première diffusion il y a 5 heures", "fr").map(|x| x.0),
+            Some(PublishKind::Premiered)
+        );
+        assert_eq!(
+            parse("hace 2 meses", "es").map(|x| x.1),
+            Some(2 * MONTH + MONTH / 2)
+        );
+        assert_eq!(parse("há 4 dias", "pt-BR").map(|x| x.1), Some(4 * DAY + DAY / 2));
+        assert_eq!(
+            parse("3 недели назад", "ru").map(|x| x.1),
+            Some(3 * WEEK + WEEK / 2)
+        );
+        assert_eq!(
+            parse("Трансляция закончилась 2 дня назад", "ru").map(|x| x.0),
+            Some(PublishKind::Streamed)
+        );
+        assert_eq!(
+            parse("2 日前 に配信済み", "ja"),
+            Some((PublishKind::Streamed, 2 * DAY + DAY / 2, DAY / 2))
+        );
+        assert_eq!(parse("3주 전", "ko").map(|x| x.1), Some(3 * WEEK + WEEK / 2));
+        assert_eq!(parse("5 ore fa", "it").map(|x| x.1), Some(5 * HOUR + HOUR / 2));
+    }
+
+    #[test]
+    fn test_published_range() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(YEAR);
+        let published = PublishedTime::parse("3 weeks ago", "en", now).unwrap();
+        let range = published.range().unwrap();
+        assert_eq!(range.start, now - Duration::from_secs(4 * WEEK));
+        assert_eq!(range.end, now - Duration::from_secs(3 * WEEK));
+        assert!(range.contains(&published.timestamp));
+
+        let published = PublishedTime {
+            uncertainty: Duration::MAX,
+            ..published
+        };
+        assert_eq!(published.range(), None);
+    }
+}
@@ -1,7 +1,28 @@
-use serde::Deserialize;
+use std::{
+    collections::HashSet,
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::{api::crawl_object_for_string, client::Client, endpoints, error::Error, util::AsciiStr};
+use crate::{
+    api::{
+        count::{parse_count, parse_count_text, Count},
+        crawl_object_for_string,
+        duration::parse_length,
+        parse_text,
+        published::PublishedTime,
+    },
+    client::{Client, ClientVariant},
+    config::Locale,
+    endpoints,
+    error::Error,
+};
+
+pub mod filter;
+pub use filter::SearchFilters;
 
 const VIDEO_JSON_KEYS: &[&str] = &[
     "videoId",
@@ -11,6 +32,15 @@ const VIDEO_JSON_KEYS: &[&str] = &[
     "navigationEndpoint",
 ];
 
+/// Upcoming streams and premieres don't always have a view count yet.
+const UPCOMING_VIDEO_JSON_KEYS: &[&str] = &[
+    "videoId",
+    "thumbnail",
+    "title",
+    "upcomingEventData",
+    "navigationEndpoint",
+];
+
 const SHORT_JSON_KEYS: &[&str] = &[
     "videoId",
     "thumbnail",
@@ -22,6 +52,22 @@ const SHORT_JSON_KEYS: &[&str] = &[
 const CHANNEL_JSON_KEYS: &[&str] = &["channelId", "title", "navigationEndpoint"];
 const CHANNEL_INGORE_JSON: &[&str] = &["navigationEndpoint"];
 
+const PLAYLIST_JSON_KEYS: &[&str] = &["playlistId", "title", "thumbnails", "navigationEndpoint"];
+/// The newer view model `YouTube` is moving playlists (and other things) over to.
+const LOCKUP_JSON_KEYS: &[&str] = &["contentId", "contentType", "metadata"];
+const LOCKUP_CONTENT_TYPE_PLAYLIST: &str = "LOCKUP_CONTENT_TYPE_PLAYLIST";
+
+/// Renderers ads are shown in. Anything inside them is an ad, even if it looks like a
+/// regular video.
+const PROMOTED_RENDERERS: &[&str] = &["adSlotRenderer", "promotedVideoRenderer", "searchPyvRenderer"];
+
+/// Where continuation results usually are.
+const CONTINUATION_CONTENTS_PATH: &str =
+    "$.onResponseReceivedCommands[0].appendContinuationItemsAction.continuationItems[0].itemSectionRenderer.contents";
+
+const SHOWING_RESULTS_FOR_JSON_KEYS: &[&str] = &["correctedQuery", "originalQuery"];
+const DID_YOU_MEAN_JSON_KEYS: &[&str] = &["correctedQuery", "didYouMean"];
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Thumbnail {
     pub url:    String,
@@ -29,18 +75,139 @@ pub struct Thumbnail {
     pub height: Option<u32>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct SearchResults {
     /// The total estimated number of results to be found.
     pub estimated_results: u64,
-    /// Search refinements (corrections, similar searches, etc.)
+    /// Search refinements (similar searches, etc.)
     pub refinements:       Vec<String>,
+    /// Set when `YouTube` thinks the query was misspelled.
+    pub correction:        Option<SearchCorrection>,
     pub videos:            Vec<VideoResult>,
     pub shorts:            Vec<ShortResult>,
     pub channels:          Vec<ChannelResult>,
+    pub playlists:         Vec<PlaylistResult>,
+    pub movies:            Vec<MovieResult>,
+    /// Mixes (also called radios) of videos based on a video, channel, or topic.
+    pub mixes:             Vec<MixResult>,
+    /// Every result in the order `YouTube` ranked them, with shelves (e.g. "People also
+    /// watched") kept together. The results in here are also in the lists above.
+    pub items:             Vec<SearchItem>,
+    /// Ads and other paid placements. These are kept out of every other list.
+    pub ads:               Vec<PromotedResult>,
+    /// Results that were left out because they couldn't be parsed. Always empty when
+    /// parsing with [`ParseMode::Strict`].
+    pub parse_errors:      Vec<ParseError>,
+
+    /// The continuation parameter that allows for continuing the search, empty after the
+    /// last page.
+    continuation: String,
+    /// The query and parameters that were searched for.
+    query:        String,
+    params:       Option<String>,
+    /// IDs of every result from every page so far.
+    seen:         HashSet<String>,
+    /// The client the search was made with.
+    variant:      ClientVariant,
+    locale:       Locale,
+    parse_mode:   ParseMode,
+}
 
-    /// The continuation parameter that allows for continuing the search
+/// How to deal with results that fail to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Leave out results that fail to parse, recording why in
+    /// [`SearchResults::parse_errors`].
+    #[default]
+    Lenient,
+    /// Fail on the first result that doesn't parse. Useful for noticing when `YouTube`
+    /// changes something.
+    Strict,
+}
+
+/// A result that failed to parse and was left out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// Where the result is in the response (e.g.
+    /// `$.contents.twoColumnSearchResultsRenderer ...contents[3].videoRenderer`).
+    pub path:    String,
+    /// The result's JSON.
+    pub value:   Value,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}: {}", self.path, self.message) }
+}
+
+/// Everything needed to pick a search back up where it left off, even after a restart.
+/// Get one with [`SearchResults::cursor`] and serialize it with your serde format of
+/// choice.
+///
+/// The IDs of the results already seen are kept too, so pages after resuming are still
+/// de-duplicated against them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchCursor {
+    query:        String,
+    params:       Option<String>,
     continuation: String,
+    variant:      ClientVariant,
+    locale:       Locale,
+    #[serde(default)]
+    seen:         HashSet<String>,
+}
+
+/// What to do with the current results when continuing a search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContinuationMode {
+    /// Replace the current results with the next page.
+    #[default]
+    Replace,
+    /// Add the next page to the current results.
+    Accumulate,
+}
+
+/// A spelling correction `YouTube` made or suggested for a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchCorrection {
+    /// The query as it was searched for.
+    pub original_query:  String,
+    /// What `YouTube` thinks the query should be.
+    pub corrected_query: String,
+    /// `true` if the results are for [`Self::corrected_query`] ("Showing results for"),
+    /// `false` if they're still for [`Self::original_query`] ("Did you mean").
+    pub auto_corrected:  bool,
+}
+
+impl SearchCorrection {
+    /// Look for a `showingResultsForRenderer` or `didYouMeanRenderer`.
+    fn parse(contents: &Value, query: &str) -> Option<Self> {
+        let (renderer, auto_corrected) =
+            crawl_for_objects_containing_keys(contents, SHOWING_RESULTS_FOR_JSON_KEYS, [])
+                .first()
+                .map(|x| (*x, true))
+                .or_else(|| {
+                    crawl_for_objects_containing_keys(contents, DID_YOU_MEAN_JSON_KEYS, [])
+                        .first()
+                        .map(|x| (*x, false))
+                })?;
+
+        let corrected_query = renderer["correctedQueryEndpoint"]["searchEndpoint"]["query"]
+            .as_str()
+            .map(str::to_string)
+            .or_else(|| parse_text(&renderer["correctedQuery"]))?;
+        let original_query = renderer["originalQueryEndpoint"]["searchEndpoint"]["query"]
+            .as_str()
+            .map(str::to_string)
+            .or_else(|| parse_text(&renderer["originalQuery"]))
+            .unwrap_or_else(|| query.to_string());
+
+        Some(Self {
+            original_query,
+            corrected_query,
+            auto_corrected,
+        })
+    }
 }
 
 impl SearchResults {
@@ -50,11 +217,71 @@ impl SearchResults {
         query: &str,
         params: Option<&str>,
     ) -> Result<SearchResults, Error> {
-        // Todo: Figure out what the params are and their format.
-        Self::from_search_results(&endpoints::search(client, query, params).await?)
+        let results = endpoints::search(client, query, params).await?;
+        Self::from_search_results(&results, query, params, client)
+    }
+
+    /// Get a cursor pointing at the next page of results.
+    #[must_use]
+    pub fn cursor(&self) -> SearchCursor {
+        SearchCursor {
+            query:        self.query.clone(),
+            params:       self.params.clone(),
+            continuation: self.continuation.clone(),
+            variant:      self.variant,
+            locale:       self.locale.clone(),
+            seen:         self.seen.clone(),
+        }
+    }
+
+    /// Get the page of results a cursor points at, using the client variant and locale
+    /// the search was started with.
+    pub(crate) async fn resume(
+        client: &Client,
+        cursor: &SearchCursor,
+    ) -> Result<Option<SearchResults>, Error> {
+        // Continuations only make sense to the client they came from.
+        let client = if client.variant == cursor.variant && client.locale == cursor.locale {
+            client.clone()
+        } else {
+            Client::new(cursor.variant, cursor.locale.clone(), client.parse_mode)?
+        };
+
+        if cursor.continuation.is_empty() {
+            return Ok(None);
+        }
+
+        let results = endpoints::search_continuation(&client, &cursor.continuation).await?;
+        let Some(page) = Self::from_continuation_search_results(&results, &client)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self::from_cursor_page(cursor, page)))
+    }
+
+    /// Start the results over from the page a cursor points at, leaving out anything that
+    /// was seen before the cursor was made.
+    fn from_cursor_page(cursor: &SearchCursor, page: SearchResults) -> SearchResults {
+        let mut results = SearchResults {
+            query: cursor.query.clone(),
+            params: cursor.params.clone(),
+            seen: cursor.seen.clone(),
+            variant: page.variant,
+            locale: page.locale.clone(),
+            parse_mode: page.parse_mode,
+            ..Default::default()
+        };
+        results.merge_page(page, ContinuationMode::Replace);
+        results
     }
 
-    fn from_search_results(results: &Value) -> Result<SearchResults, Error> {
+    /// `client` is the client that made the search.
+    fn from_search_results(
+        results: &Value,
+        query: &str,
+        params: Option<&str>,
+        client: &Client,
+    ) -> Result<SearchResults, Error> {
         let contents = &results
             .get("contents")
             .ok_or(Error::JsonParse("No 'contents' found".to_string()))?;
@@ -72,10 +299,14 @@ impl SearchResults {
         let mut results = SearchResults {
             estimated_results,
             refinements,
+            correction: SearchCorrection::parse(contents, query),
             continuation,
-            videos: Vec::new(),
-            shorts: Vec::new(),
-            channels: Vec::new(),
+            query: query.to_string(),
+            params: params.map(str::to_string),
+            variant: client.variant,
+            locale: client.locale.clone(),
+            parse_mode: client.parse_mode,
+            ..Default::default()
         };
 
         // We'd run in to errors parsing nothing (or just junk "for you" results) if we don't stop
@@ -84,45 +315,109 @@ impl SearchResults {
             return Ok(results);
         }
 
-        let videos = crawl_for_objects_containing_keys(contents, VIDEO_JSON_KEYS, []);
-        let shorts = crawl_for_objects_containing_keys(contents, SHORT_JSON_KEYS, ["title"]);
-        let channels = crawl_for_objects_containing_keys(contents, CHANNEL_JSON_KEYS, ["videoId"]);
+        results.parse_contents(contents, "$.contents")?;
 
-        for video in videos {
-            results.videos.push(VideoResult::parse_video(video)?);
-        }
+        Ok(results)
+    }
 
-        for short in shorts {
-            results.shorts.push(ShortResult::parse_short(short)?);
-        }
+    /// Search again for the same query with spelling correction turned off, keeping any
+    /// other filters the original search used.
+    pub(crate) async fn search_exact_query(&self, client: &Client) -> Result<SearchResults, Error> {
+        let filters = match &self.params {
+            Some(params) => SearchFilters::from_params(params)?,
+            None => SearchFilters::new(),
+        };
+        let params = filters.exact_query(true).to_params();
+        Self::search(client, &self.query, Some(&params)).await
+    }
 
-        for channel in channels {
-            results.channels.push(ChannelResult::parse_channel(channel)?);
-        }
+    /// Find and parse every kind of result in `contents`, adding them to the results.
+    /// `path` is where `contents` is in the response.
+    fn parse_contents(&mut self, contents: &Value, path: &str) -> Result<(), Error> {
+        let mut parser = ItemParser::new(self.parse_mode, &self.locale, path);
+        let mut items = Vec::new();
+        parser.parse_items(contents, &mut items)?;
+        self.add_items(items);
+        self.ads.append(&mut parser.ads);
+        self.parse_errors.append(&mut parser.errors);
+
+        Ok(())
+    }
 
-        Ok(results)
+    /// Add items to the results, leaving out any that were already added, whether on an
+    /// earlier page or earlier on this one.
+    fn add_items(&mut self, mut items: Vec<SearchItem>) {
+        remove_seen(&mut self.seen, &mut items);
+        for item in &items {
+            self.add_to_kind(item);
+        }
+        self.items.append(&mut items);
     }
 
-    /// Continue the search using the continuation, returns a bool indicating if any new
-    /// results were able to be found.
-    pub(crate) async fn continue_search(&mut self, client: &Client) -> Result<bool, Error> {
-        let results = endpoints::search_continuation(client, &self.continuation).await?;
-        let Some(x) = Self::from_continuation_search_results(&results)? else {return Ok(false)};
+    /// Add a copy of an item to the list for its kind, e.g. videos go in `videos`.
+    fn add_to_kind(&mut self, item: &SearchItem) {
+        match item {
+            SearchItem::Video(x) => self.videos.push(x.clone()),
+            SearchItem::Short(x) => self.shorts.push(x.clone()),
+            SearchItem::Channel(x) => self.channels.push(x.clone()),
+            SearchItem::Playlist(x) => self.playlists.push(x.clone()),
+            SearchItem::Movie(x) => self.movies.push(x.clone()),
+            SearchItem::Mix(x) => self.mixes.push(x.clone()),
+            SearchItem::Shelf { items, .. } => {
+                for item in items {
+                    self.add_to_kind(item);
+                }
+            }
+        }
+    }
 
-        if x.videos.is_empty() && x.shorts.is_empty() {
+    /// Continue the search using the continuation, returns `false` once there are no more
+    /// pages.
+    pub(crate) async fn continue_search(
+        &mut self,
+        client: &Client,
+        mode: ContinuationMode,
+    ) -> Result<bool, Error> {
+        if self.continuation.is_empty() {
             return Ok(false);
         }
 
-        self.videos = x.videos;
-        self.shorts = x.shorts;
-        self.estimated_results = x.estimated_results;
-        self.continuation = x.continuation;
+        let results = endpoints::search_continuation(client, &self.continuation).await?;
+        let Some(page) = Self::from_continuation_search_results(&results, client)? else {
+            return Ok(false);
+        };
 
+        self.merge_page(page, mode);
         Ok(true)
     }
 
-    pub(crate) fn from_continuation_search_results(results: &Value) -> Result<Option<SearchResults>, Error> {
-        let contents = results
+    /// Merge the next page of results in, leaving out anything that was already on a
+    /// previous page.
+    fn merge_page(&mut self, page: SearchResults, mode: ContinuationMode) {
+        if mode == ContinuationMode::Replace {
+            self.videos.clear();
+            self.shorts.clear();
+            self.channels.clear();
+            self.playlists.clear();
+            self.movies.clear();
+            self.mixes.clear();
+            self.items.clear();
+            self.ads.clear();
+            self.parse_errors.clear();
+        }
+
+        self.add_items(page.items);
+        self.ads.extend(page.ads);
+        self.parse_errors.extend(page.parse_errors);
+        self.estimated_results = page.estimated_results;
+        self.continuation = page.continuation;
+    }
+
+    pub(crate) fn from_continuation_search_results(
+        results: &Value,
+        client: &Client,
+    ) -> Result<Option<SearchResults>, Error> {
+        let (contents, path) = results
             .get("onResponseReceivedCommands")
             .and_then(|x| x.get(0))
             .and_then(|x| x.get("appendContinuationItemsAction"))
@@ -130,49 +425,35 @@ impl SearchResults {
             .and_then(|x| x.get(0))
             .and_then(|x| x.get("itemSectionRenderer"))
             .and_then(|x| x.get("contents"))
-            .or(crawl_for_contents(results, None));
+            .map(|x| (x, CONTINUATION_CONTENTS_PATH))
+            .or_else(|| crawl_for_contents(results, None).map(|x| (x, "$..contents")))
+            .unwrap_or((results, "$"));
 
         let estimated_results = results["estimatedResults"]
             .as_str()
             .and_then(|x| x.parse::<u64>().ok())
             .ok_or(Error::JsonParse("No estimated result count found".into()))?;
 
-        let videos = crawl_for_objects_containing_keys(contents.unwrap_or(results), VIDEO_JSON_KEYS, []);
-        let shorts =
-            crawl_for_objects_containing_keys(contents.unwrap_or(results), SHORT_JSON_KEYS, ["title"]);
-        let channels =
-            crawl_for_objects_containing_keys(contents.unwrap_or(results), CHANNEL_JSON_KEYS, ["videoId"]);
-
-        if videos.is_empty() && shorts.is_empty() && channels.is_empty() {
-            return Ok(None);
-        }
-
-        let continuation = crawl_for_continuation(results)
-            .map(str::to_string)
-            .ok_or(Error::JsonParse("No continuation found".into()))?;
-
         let mut continuation_results = SearchResults {
             estimated_results,
-            refinements: Vec::new(),
-            continuation,
-            videos: Vec::new(),
-            shorts: Vec::new(),
-            channels: Vec::new(),
+            variant: client.variant,
+            locale: client.locale.clone(),
+            parse_mode: client.parse_mode,
+            ..Default::default()
         };
-
-        for video in videos {
-            continuation_results.videos.push(VideoResult::parse_video(video)?);
-        }
-
-        for short in shorts {
-            continuation_results.shorts.push(ShortResult::parse_short(short)?);
-        }
-
-        for channel in channels {
-            continuation_results
-                .channels
-                .push(ChannelResult::parse_channel(channel)?);
+        continuation_results.parse_contents(contents, path)?;
+
+        // A page can end up without any results (e.g. when it only had ads) and still lead
+        // on to more, so it's the continuation that says whether the search is over. The
+        // last page is still returned if anything on it failed to parse, so that's known.
+        let continuation = crawl_for_continuation(results).map(str::to_string);
+        if continuation.is_none()
+            && continuation_results.items.is_empty()
+            && continuation_results.parse_errors.is_empty()
+        {
+            return Ok(None);
         }
+        continuation_results.continuation = continuation.unwrap_or_default();
 
         Ok(Some(continuation_results))
     }
@@ -212,6 +493,232 @@ fn crawl_for_contents<'a>(results: &'a Value, name: Option<&'a String>) -> Optio
     None
 }
 
+/// A single search result, or a shelf of them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchItem {
+    Video(VideoResult),
+    Short(ShortResult),
+    Channel(ChannelResult),
+    Playlist(PlaylistResult),
+    Movie(MovieResult),
+    Mix(MixResult),
+    /// A group of results shown together under a title (e.g. "Latest from Linus Tech
+    /// Tips").
+    Shelf {
+        title: Option<String>,
+        items: Vec<SearchItem>,
+    },
+}
+
+impl SearchItem {
+    /// The video, channel, or playlist ID of the result. Shelves don't have one.
+    #[must_use]
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Self::Video(x) => Some(&x.id),
+            Self::Short(x) => Some(&x.id),
+            Self::Channel(x) => Some(&x.id),
+            Self::Playlist(x) => Some(&x.id),
+            Self::Movie(x) => Some(&x.id),
+            Self::Mix(x) => Some(&x.id),
+            Self::Shelf { .. } => None,
+        }
+    }
+
+    /// Parse results that can only be told apart from others by the name of their
+    /// renderer.
+    fn parse_renderer(name: &str, object: &Value, hl: &str) -> Result<Option<Self>, Error> {
+        let item = match name {
+            // Movies look a lot like videos.
+            "movieRenderer" => Self::Movie(MovieResult::parse_movie(object, hl)?),
+            "radioRenderer" | "compactRadioRenderer" => Self::Mix(MixResult::parse_radio(object, hl)?),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(item))
+    }
+
+    /// Parse `object` if it's one of the supported kinds of results. `hl` is the language
+    /// the response is in.
+    fn parse(object: &Value, hl: &str) -> Result<Option<Self>, Error> {
+        let item = if has_keys(object, VIDEO_JSON_KEYS, []) || has_keys(object, UPCOMING_VIDEO_JSON_KEYS, [])
+        {
+            Self::Video(VideoResult::parse_video(object, hl)?)
+        } else if has_keys(object, SHORT_JSON_KEYS, ["title"]) {
+            Self::Short(ShortResult::parse_short(object, hl)?)
+        } else if has_keys(object, CHANNEL_JSON_KEYS, ["videoId"]) {
+            Self::Channel(ChannelResult::parse_channel(object, hl)?)
+        } else if has_keys(object, PLAYLIST_JSON_KEYS, []) {
+            Self::Playlist(PlaylistResult::parse_playlist(object, hl)?)
+        } else if has_keys(object, LOCKUP_JSON_KEYS, [])
+            && object["contentType"].as_str() == Some(LOCKUP_CONTENT_TYPE_PLAYLIST)
+        {
+            if object["contentId"].as_str().is_some_and(is_mix_id) {
+                Self::Mix(MixResult::parse_lockup(object, hl)?)
+            } else {
+                Self::Playlist(PlaylistResult::parse_lockup(object, hl)?)
+            }
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(item))
+    }
+}
+
+/// Remove items whose ID is in `seen`, and any shelves left empty by that, adding the IDs
+/// of the items that are kept.
+fn remove_seen(seen: &mut HashSet<String>, items: &mut Vec<SearchItem>) {
+    items.retain_mut(|item| {
+        match item {
+            SearchItem::Shelf { items, .. } => {
+                remove_seen(seen, items);
+                !items.is_empty()
+            }
+            _ => {
+                match item.id() {
+                    Some(id) => seen.insert(id.to_string()),
+                    None => true,
+                }
+            }
+        }
+    });
+}
+
+/// Renderers that group results together.
+const SHELF_RENDERERS: &[&str] = &[
+    "shelfRenderer",
+    "reelShelfRenderer",
+    "richShelfRenderer",
+    "gridShelfViewModel",
+];
+
+/// Walks a response in order, collecting every result and shelf found, and keeping track
+/// of where it is so results that fail to parse can be pointed at.
+pub(super) struct ItemParser {
+    mode:              ParseMode,
+    hl:                String,
+    /// The JSON path of the value being parsed.
+    path:              String,
+    pub(super) ads:    Vec<PromotedResult>,
+    pub(super) errors: Vec<ParseError>,
+}
+
+impl ItemParser {
+    /// `path` is where the contents being parsed are in the response (e.g. `$.contents`).
+    pub(super) fn new(mode: ParseMode, locale: &Locale, path: &str) -> Self {
+        Self {
+            mode,
+            hl: locale.hl.clone(),
+            path: path.to_string(),
+            ads: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub(super) fn parse_items(&mut self, contents: &Value, items: &mut Vec<SearchItem>) -> Result<(), Error> {
+        if let Some(object) = contents.as_object() {
+            match SearchItem::parse(contents, &self.hl) {
+                Ok(Some(item)) => {
+                    items.push(item);
+                    return Ok(());
+                }
+                Ok(None) => {}
+                Err(e) => return self.failed(contents, e),
+            }
+
+            for (name, x) in object {
+                let len = self.path.len();
+                self.path.push('.');
+                self.path.push_str(name);
+                let result = self.parse_field(name, x, items);
+                self.path.truncate(len);
+                result?;
+            }
+        } else if let Some(x) = contents.as_array() {
+            for (i, x) in x.iter().enumerate() {
+                let len = self.path.len();
+                self.path.push_str(&format!("[{i}]"));
+                let result = self.parse_items(x, items);
+                self.path.truncate(len);
+                result?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_field(&mut self, name: &str, x: &Value, items: &mut Vec<SearchItem>) -> Result<(), Error> {
+        if PROMOTED_RENDERERS.contains(&name) {
+            // `searchPyvRenderer`s hold a list of ads.
+            match x["ads"].as_array() {
+                Some(x) => {
+                    self.ads
+                        .extend(x.iter().filter_map(|x| PromotedResult::parse(x, &self.hl)))
+                }
+                None => self.ads.extend(PromotedResult::parse(x, &self.hl)),
+            }
+            return Ok(());
+        }
+
+        match SearchItem::parse_renderer(name, x, &self.hl) {
+            Ok(Some(item)) => items.push(item),
+            Ok(None) if SHELF_RENDERERS.contains(&name) => {
+                let mut shelf_items = Vec::new();
+                self.parse_items(x, &mut shelf_items)?;
+                if !shelf_items.is_empty() {
+                    items.push(SearchItem::Shelf {
+                        title: parse_shelf_title(x),
+                        items: shelf_items,
+                    });
+                }
+            }
+            Ok(None) => self.parse_items(x, items)?,
+            Err(e) => self.failed(x, e)?,
+        }
+
+        Ok(())
+    }
+
+    /// Record a result that failed to parse, or give up if parsing strictly.
+    fn failed(&mut self, value: &Value, error: Error) -> Result<(), Error> {
+        let error = ParseError {
+            path:    self.path.clone(),
+            value:   value.clone(),
+            message: match error {
+                Error::JsonParse(x) => x,
+                e => e.to_string(),
+            },
+        };
+
+        match self.mode {
+            ParseMode::Strict => Err(Error::ItemParse(Box::new(error))),
+            ParseMode::Lenient => {
+                self.errors.push(error);
+                Ok(())
+            }
+        }
+    }
+}
+
+fn parse_shelf_title(shelf: &Value) -> Option<String> {
+    parse_text(&shelf["title"]).or_else(|| {
+        // View models keep it in a header renderer of some sort.
+        let header = shelf["header"].as_object()?.values().next()?;
+        parse_text(&header["title"]).or_else(|| parse_text(&header["headline"]))
+    })
+}
+
+/// Check if an object contains all of `keys` and none of `omit_keys`.
+fn has_keys(object: &Value, keys: impl AsRef<[&'static str]>, omit_keys: impl AsRef<[&'static str]>) -> bool {
+    let Some(object) = object.as_object() else {
+        return false;
+    };
+
+    keys.as_ref().iter().all(|key| object.contains_key(*key))
+        && omit_keys.as_ref().iter().all(|key| !object.contains_key(*key))
+}
+
 /// Crawl search results for objects that contain or don't contain provided keys. Returns
 /// a Vec of objects.
 fn crawl_for_objects_containing_keys(
@@ -224,12 +731,7 @@ fn crawl_for_objects_containing_keys(
     let mut found_objects = Vec::new();
 
     if let Some(contents_o) = contents.as_object() {
-        // This is dumb
-        let condition = keys.iter().all(|key| contents_o.contains_key(*key))
-            && omit_keys.iter().all(|key| !contents_o.contains_key(*key));
-
-        if condition {
-            dbg!(&contents["videoId"]);
+        if has_keys(contents, keys, omit_keys) {
             found_objects.push(contents);
         } else {
             for (_, object) in contents_o {
@@ -251,7 +753,7 @@ fn crawl_for_objects_containing_keys(
     found_objects
 }
 
-fn crawl_for_continuation(contents: &Value) -> Option<&str> {
+pub(super) fn crawl_for_continuation(contents: &Value) -> Option<&str> {
     if let Some(contents) = contents.as_object() {
         let contents_s = contents
             .get("continuationCommand")
@@ -287,20 +789,193 @@ fn parse_url(val: &Value) -> Option<String> {
         .map(|path| "https://www.youtube.com".to_string() + path)
 }
 
+/// An ad or other paid placement found in search results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromotedResult {
+    /// The promoted video, if the ad is for one.
+    pub video_id:   Option<String>,
+    pub title:      Option<String>,
+    /// Where the ad leads, this can be off of `YouTube`.
+    pub url:        Option<String>,
+    /// The advertiser or the channel of the promoted video.
+    pub advertiser: Option<String>,
+    /// Set when the ad is laid out like a regular video result.
+    pub video:      Option<VideoResult>,
+}
+
+impl PromotedResult {
+    /// Parse the contents of an ad renderer. Ads come in many different layouts so this
+    /// only takes what it can find, returning `None` if there's nothing at all.
+    fn parse(ad: &Value, hl: &str) -> Option<Self> {
+        // An ad that doesn't parse as a video is still an ad, so don't fail because of it.
+        let video = crawl_for_objects_containing_keys(ad, VIDEO_JSON_KEYS, [])
+            .first()
+            .and_then(|x| VideoResult::parse_video(x, hl).ok());
+
+        let video_id = crawl_for_objects_containing_keys(ad, ["videoId"], [])
+            .iter()
+            .find_map(|x| x["videoId"].as_str())
+            .map(str::to_string);
+        let renderer = crawl_for_objects_containing_keys(ad, ["navigationEndpoint"], [])
+            .into_iter()
+            .find(|x| has_keys(x, ["title"], []) || has_keys(x, ["headline"], []))
+            .unwrap_or(ad);
+
+        let title = renderer
+            .get("title")
+            .or_else(|| renderer.get("headline"))
+            .and_then(parse_text);
+        // Ads for other websites link out with a `urlEndpoint` instead.
+        let url = renderer["navigationEndpoint"]["urlEndpoint"]["url"]
+            .as_str()
+            .map(str::to_string)
+            .or_else(|| parse_url(renderer));
+        let advertiser = renderer
+            .get("longBylineText")
+            .or_else(|| renderer.get("websiteText"))
+            .and_then(parse_text);
+
+        if video_id.is_none() && title.is_none() && url.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            video_id,
+            title,
+            url,
+            advertiser,
+            video,
+        })
+    }
+}
+
+/// A badge shown next to a result or its channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Badge {
+    Verified,
+    VerifiedArtist,
+    Live,
+    FourK,
+    ClosedCaptions,
+    New,
+    /// Any other badge, with the label `YouTube` gave it.
+    Other(String),
+}
+
+impl Badge {
+    /// Parse a list of `metadataBadgeRenderer`s, such as `badges` or `ownerBadges`.
+    fn parse_badges(badges: &Value) -> Vec<Self> {
+        let Some(badges) = badges.as_array() else {
+            return Vec::new();
+        };
+
+        badges
+            .iter()
+            .filter_map(|x| x.get("metadataBadgeRenderer"))
+            .filter_map(|badge| {
+                let style = badge["style"].as_str().unwrap_or_default();
+                let label = badge["label"].as_str().or_else(|| badge["tooltip"].as_str());
+                Some(match (style, label) {
+                    ("BADGE_STYLE_TYPE_VERIFIED", _) => Self::Verified,
+                    ("BADGE_STYLE_TYPE_VERIFIED_ARTIST", _) => Self::VerifiedArtist,
+                    ("BADGE_STYLE_TYPE_LIVE_NOW", _) => Self::Live,
+                    (_, Some("4K")) => Self::FourK,
+                    (_, Some("CC")) => Self::ClosedCaptions,
+                    (_, Some("New")) => Self::New,
+                    (_, Some(label)) => Self::Other(label.to_string()),
+                    (_, None) => return None,
+                })
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct VideoResult {
-    pub id:         String,
+    pub id:                  String,
     /// The watch url of the video
-    pub url:        String,
-    pub title:      String,
-    pub channel:    String,
-    /// May be inaccurate due to variance in responses from YouTube.
-    pub view_count: u64,
-    pub thumbnails: Vec<Thumbnail>,
+    pub url:                 String,
+    pub title:               String,
+    pub channel:             String,
+    pub channel_id:          Option<String>,
+    pub channel_avatar:      Vec<Thumbnail>,
+    /// Badges next to the channel name, like [`Badge::Verified`].
+    pub channel_badges:      Vec<Badge>,
+    /// `None` for live and upcoming videos, which only show how many are watching or
+    /// waiting.
+    pub view_count:          Option<Count>,
+    pub thumbnails:          Vec<Thumbnail>,
+    /// The length as displayed on the thumbnail (e.g. "12:05").
+    pub length_text:         Option<String>,
+    /// [`VideoResult::length_text`] as a duration. `None` for live streams.
+    pub length:              Option<Duration>,
+    /// When the video was published as displayed (e.g. "3 weeks ago").
+    pub published_text:      Option<String>,
+    /// [`VideoResult::published_text`] as a time.
+    pub published:           Option<PublishedTime>,
+    /// The part of the description shown under the result.
+    pub description_snippet: Option<String>,
+    /// Badges under the result, like [`Badge::FourK`].
+    pub badges:              Vec<Badge>,
+    pub live_status:         LiveStatus,
+}
+
+/// Whether a video is or will be live. Live and upcoming videos have no
+/// [`VideoResult::view_count`] as `YouTube` only gives a watching/waiting count for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LiveStatus {
+    /// A regular upload or a stream that has ended.
+    #[default]
+    None,
+    /// Currently live.
+    Live { concurrent_viewers: Option<Count> },
+    /// A scheduled live stream.
+    Upcoming { scheduled_start: Option<SystemTime> },
+    /// A scheduled premiere of an uploaded video.
+    Premiere { scheduled_start: Option<SystemTime> },
+}
+
+impl LiveStatus {
+    fn parse(video: &Value, hl: &str) -> Self {
+        if let Some(event) = video.get("upcomingEventData") {
+            let scheduled_start = event["startTime"]
+                .as_str()
+                .and_then(|x| x.parse::<u64>().ok())
+                .map(|x| UNIX_EPOCH + Duration::from_secs(x));
+
+            // Premieres are of uploaded videos so they already have a length, scheduled streams
+            // don't. The event text is checked as well in case the length is ever left out.
+            let is_premiere = video.get("lengthText").is_some()
+                || parse_text(&event["upcomingEventText"])
+                    .is_some_and(|x| x.to_lowercase().starts_with("premiere"));
+
+            return if is_premiere {
+                Self::Premiere { scheduled_start }
+            } else {
+                Self::Upcoming { scheduled_start }
+            };
+        }
+
+        let live_badge = Badge::parse_badges(&video["badges"]).contains(&Badge::Live);
+        let live_overlay = video["thumbnailOverlays"].as_array().is_some_and(|overlays| {
+            overlays
+                .iter()
+                .any(|x| x["thumbnailOverlayTimeStatusRenderer"]["style"].as_str() == Some("LIVE"))
+        });
+
+        if live_badge || live_overlay {
+            let concurrent_viewers = video
+                .get("viewCountText")
+                .and_then(|x| parse_count_text(x, hl).ok());
+            return Self::Live { concurrent_viewers };
+        }
+
+        Self::None
+    }
 }
 
 impl VideoResult {
-    fn parse_video(video: &Value) -> Result<Self, Error> {
+    fn parse_video(video: &Value, hl: &str) -> Result<Self, Error> {
         let id = video["videoId"]
             .as_str()
             .map(str::to_string)
@@ -316,6 +991,17 @@ impl VideoResult {
             .ok_or(Error::JsonParse("No channel found".into()))?
             .to_string();
 
+        let channel_id = parse_byline_channel_id(&video["longBylineText"])
+            .or_else(|| parse_byline_channel_id(&video["ownerText"]))
+            .or_else(|| parse_byline_channel_id(&video["shortBylineText"]));
+
+        let channel_avatar = video["channelThumbnailSupportedRenderers"]["channelThumbnailWithLinkRenderer"]
+            ["thumbnail"]
+            .get("thumbnails")
+            .or_else(|| video["channelThumbnail"].get("thumbnails"))
+            .and_then(|x| serde_json::from_value(x.clone()).ok())
+            .unwrap_or_default();
+
         let thumbnails = serde_json::from_value(
             video["thumbnail"]
                 .get("thumbnails")
@@ -324,61 +1010,63 @@ impl VideoResult {
         )
         .map_err(|e| Error::JsonParse(e.to_string()))?;
 
+        let live_status = LiveStatus::parse(video, hl);
+
         // Some youtube view counts are accurate and some just suck, so we have to parse them
         // differently despite them haviving a specific key for this other (worse) kind of count.
-        // Additonally `simpleText` doesn't exist for what I assume are live streams.
-        let view_count_text = video
-            .get("viewCountText")
-            .and_then(|x| {
-                x.get("simpleText")
-                    .or_else(|| x.get("runs").and_then(|x| x[0].get("text")))
-                    .and_then(Value::as_str)
-            })
-            .ok_or(Error::JsonParse("No view count".into()))?;
-
-        let view_count = match view_count_text {
-            "No views" => 0,
-            _ if view_count_text.ends_with_one_of(['K', 'M', 'B']) => {
-                let x = match view_count_text {
-                    _ if view_count_text.ends_with('K') => 1_000.0f64,
-                    _ if view_count_text.ends_with('M') => 1_000_000.0f64,
-                    _ if view_count_text.ends_with('B') => 1_000_000_000.0f64,
-                    _ => unreachable!(),
-                };
-
-                let view_count_text = view_count_text
-                    .remove_matches(['K', 'M', 'B'])
-                    .replace_all(["views", "view"], "");
-                let view_count_text = view_count_text.trim();
-                let views = x * view_count_text.parse::<f64>().map_err(|e| {
-                    Error::JsonParse(format!(
-                        "Unparsable view count: \"{}\": {e}",
-                        view_count_text.trim()
-                    ))
-                })?;
-
-                views as u64
-            }
-            _ => {
-                let view_count_text = view_count_text
-                    .replace(',', "")
-                    .replace_all(["views", "view"], "");
-                let view_count_text = view_count_text.trim();
-                view_count_text.parse::<u64>().map_err(|e| {
-                    Error::JsonParse(format!("Unparsable view count: \"{}\": {e}", view_count_text))
-                })?
+        // Live streams show how many are watching instead of a view count, and upcoming ones
+        // how many are waiting (if anything at all), so those aren't view counts.
+        let view_count = match live_status {
+            LiveStatus::None => {
+                let view_count_text = video
+                    .get("viewCountText")
+                    .ok_or(Error::JsonParse("No view count".into()))?;
+                Some(parse_count_text(view_count_text, hl)?)
             }
+            _ => None,
         };
 
         let url = parse_url(video).ok_or_else(|| Error::JsonParse("No watch URL".into()))?;
 
+        let length_text = video.get("lengthText").and_then(parse_text);
+        // The overlay on the thumbnail has the length as well, which is all there is for
+        // videos that are shown as shorts.
+        let length = video
+            .get("lengthText")
+            .and_then(|x| parse_length(x, hl))
+            .or_else(|| {
+                video["thumbnailOverlays"]
+                    .as_array()?
+                    .iter()
+                    .find_map(|x| parse_length(&x["thumbnailOverlayTimeStatusRenderer"]["text"], hl))
+            });
+        let published_text = video.get("publishedTimeText").and_then(parse_text);
+        let published = published_text
+            .as_deref()
+            .and_then(|x| PublishedTime::parse(x, hl, SystemTime::now()));
+        let description_snippet = video["detailedMetadataSnippets"]
+            .get(0)
+            .and_then(|x| x.get("snippetText"))
+            .or_else(|| video.get("descriptionSnippet"))
+            .and_then(parse_text);
+
         Ok(Self {
             id,
             url,
             title,
             channel,
+            channel_id,
+            channel_avatar,
+            channel_badges: Badge::parse_badges(&video["ownerBadges"]),
             view_count,
             thumbnails,
+            length_text,
+            length,
+            published_text,
+            published,
+            description_snippet,
+            badges: Badge::parse_badges(&video["badges"]),
+            live_status,
         })
     }
 }
@@ -390,10 +1078,13 @@ pub struct ShortResult {
     pub headline:   String,
     pub url:        String,
     pub thumbnails: Vec<Thumbnail>,
+    pub view_count: Option<Count>,
+    /// Only known when `YouTube` gives it, which it usually doesn't for shorts.
+    pub length:     Option<Duration>,
 }
 
 impl ShortResult {
-    fn parse_short(short: &Value) -> Result<Self, Error> {
+    fn parse_short(short: &Value, hl: &str) -> Result<Self, Error> {
         let id = short["videoId"]
             .as_str()
             .map(str::to_string)
@@ -420,23 +1111,35 @@ impl ShortResult {
             headline,
             url,
             thumbnails,
+            view_count: short
+                .get("viewCountText")
+                .and_then(|x| parse_count_text(x, hl).ok()),
+            length: short.get("lengthText").and_then(|x| parse_length(x, hl)),
         })
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ChannelResult {
-    pub id:   String,
-    pub name: String,
-    pub url:  String,
+    pub id:                  String,
+    pub name:                String,
+    pub url:                 String,
+    /// The channel's handle including the `@` (e.g. "@LinusTechTips").
+    pub handle:              Option<String>,
+    pub subscriber_count:    Option<Count>,
+    pub video_count:         Option<Count>,
+    pub description_snippet: Option<String>,
+    pub avatar:              Vec<Thumbnail>,
+    /// Badges next to the channel name, like [`Badge::Verified`].
+    pub badges:              Vec<Badge>,
 }
 
 impl ChannelResult {
-    fn parse_channel(channel: &Value) -> Result<Self, Error> {
+    fn parse_channel(channel: &Value, hl: &str) -> Result<Self, Error> {
         let id = channel["channelId"]
             .as_str()
             .map(str::to_string)
-            .ok_or(Error::JsonParse("No video id found".into()))?;
+            .ok_or(Error::JsonParse("No channel id found".into()))?;
 
         let name = channel["title"]
             .get("simpleText")
@@ -446,15 +1149,1052 @@ impl ChannelResult {
 
         let url = parse_url(channel).ok_or_else(|| Error::JsonParse("No channel URL".into()))?;
 
-        Ok(Self { id, name, url })
+        let mut handle = channel["navigationEndpoint"]["browseEndpoint"]["canonicalBaseUrl"]
+            .as_str()
+            .and_then(|x| x.strip_prefix('/'))
+            .filter(|x| x.starts_with('@'))
+            .map(str::to_string);
+        let subscriber_count_text = channel.get("subscriberCountText");
+        let video_count_text = channel.get("videoCountText");
+
+        // `YouTube` shuffled these around, when `subscriberCountText` holds the handle
+        // `videoCountText` holds the subscriber count and there's no video count.
+        let (subscriber_count_text, video_count_text) = match subscriber_count_text.and_then(parse_text) {
+            Some(x) if x.starts_with('@') => {
+                handle.get_or_insert(x);
+                (video_count_text, None)
+            }
+            _ => (subscriber_count_text, video_count_text),
+        };
+        let subscriber_count = subscriber_count_text.and_then(|x| parse_count_text(x, hl).ok());
+        let video_count = video_count_text.and_then(|x| parse_count_text(x, hl).ok());
+
+        let avatar = channel["thumbnail"]["thumbnails"]
+            .as_array()
+            .map(|thumbnails| {
+                thumbnails
+                    .iter()
+                    .filter_map(|x| serde_json::from_value::<Thumbnail>(x.clone()).ok())
+                    .map(|mut x| {
+                        // Avatars are given without a scheme.
+                        if x.url.starts_with("//") {
+                            x.url.insert_str(0, "https:");
+                        }
+                        x
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            id,
+            name,
+            url,
+            handle,
+            subscriber_count,
+            video_count,
+            description_snippet: channel.get("descriptionSnippet").and_then(parse_text),
+            avatar,
+            badges: Badge::parse_badges(&channel["ownerBadges"]),
+        })
+    }
+}
+
+/// Get the channel ID a byline (e.g. `shortBylineText`) links to.
+fn parse_byline_channel_id(byline: &Value) -> Option<String> {
+    byline
+        .get("runs")
+        .and_then(|x| x.get(0))
+        .and_then(|x| x.get("navigationEndpoint"))
+        .and_then(|x| x.get("browseEndpoint"))
+        .and_then(|x| x.get("browseId").and_then(Value::as_str))
+        .map(str::to_string)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaylistResult {
+    pub id:          String,
+    /// The URL of the playlist page
+    pub url:         String,
+    pub title:       String,
+    /// Not every playlist has an owner (e.g. some auto-generated ones).
+    pub channel:     Option<String>,
+    pub channel_id:  Option<String>,
+    pub video_count: Option<Count>,
+    pub thumbnails:  Vec<Thumbnail>,
+    /// The first video in the playlist, if `YouTube` gave a preview of it.
+    pub first_video: Option<PlaylistVideoPreview>,
+}
+
+/// A video previewed as part of a playlist result. There's far less information here than
+/// in a [`VideoResult`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaylistVideoPreview {
+    pub id:     String,
+    /// The watch url of the video in the playlist
+    pub url:    String,
+    /// Not available from every kind of playlist result.
+    pub title:  Option<String>,
+    /// Not available from every kind of playlist result.
+    pub length: Option<Duration>,
+}
+
+impl PlaylistVideoPreview {
+    /// Parse a `childVideoRenderer`.
+    fn parse_child(video: &Value, hl: &str) -> Option<Self> {
+        Some(Self {
+            id:     video["videoId"].as_str()?.to_string(),
+            url:    parse_url(video)?,
+            title:  crawl_object_for_string(&video["title"], &["accessibility"]).map(str::to_string),
+            length: video.get("lengthText").and_then(|x| parse_length(x, hl)),
+        })
+    }
+}
+
+impl PlaylistResult {
+    fn new_url(id: &str) -> String { format!("https://www.youtube.com/playlist?list={id}") }
+
+    /// Parse a `playlistRenderer`.
+    fn parse_playlist(playlist: &Value, hl: &str) -> Result<Self, Error> {
+        let id = playlist["playlistId"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or(Error::JsonParse("No playlist id found".into()))?;
+
+        let title = crawl_object_for_string(&playlist["title"], &["accessibility"])
+            .ok_or(Error::JsonParse("No title found".into()))?
+            .to_string();
+
+        let byline = playlist
+            .get("longBylineText")
+            .or_else(|| playlist.get("shortBylineText"))
+            .unwrap_or(&Value::Null);
+        let channel = crawl_object_for_string(byline, CHANNEL_INGORE_JSON).map(str::to_string);
+        let channel_id = parse_byline_channel_id(byline);
+
+        let video_count = playlist["videoCount"]
+            .as_str()
+            .or_else(|| crawl_object_for_string(&playlist["videoCountText"], &[]))
+            .and_then(|x| parse_count(x, hl).ok());
+
+        // There's a list of thumbnail sets, the first one is what's shown on the result.
+        let thumbnails = serde_json::from_value(
+            playlist["thumbnails"]
+                .get(0)
+                .and_then(|x| x.get("thumbnails"))
+                .ok_or(Error::JsonParse("No thumbnails found".into()))?
+                .clone(),
+        )
+        .map_err(|e| Error::JsonParse(e.to_string()))?;
+
+        let first_video = playlist["videos"]
+            .get(0)
+            .and_then(|x| x.get("childVideoRenderer"))
+            .and_then(|x| PlaylistVideoPreview::parse_child(x, hl));
+
+        Ok(Self {
+            url: Self::new_url(&id),
+            id,
+            title,
+            channel,
+            channel_id,
+            video_count,
+            thumbnails,
+            first_video,
+        })
+    }
+
+    /// Parse a playlist `lockupViewModel`.
+    fn parse_lockup(lockup: &Value, hl: &str) -> Result<Self, Error> {
+        let id = lockup["contentId"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or(Error::JsonParse("No playlist id found".into()))?;
+
+        let metadata = &lockup["metadata"]["lockupMetadataViewModel"];
+        let title = metadata["title"]["content"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or(Error::JsonParse("No title found".into()))?;
+
+        // The first metadata row is the owner, it links to their channel when there is one.
+        let owner = metadata["metadata"]["contentMetadataViewModel"]["metadataRows"]
+            .get(0)
+            .and_then(|x| x["metadataParts"].get(0))
+            .map(|x| &x["text"]);
+        let channel_id = owner
+            .and_then(|x| x["commandRuns"].get(0))
+            .and_then(|x| x["onTap"]["innertubeCommand"]["browseEndpoint"]["browseId"].as_str())
+            .map(str::to_string);
+        // Without a link it's just text like "Updated today", so it isn't an owner.
+        let channel = channel_id
+            .as_ref()
+            .and(owner.and_then(|x| x["content"].as_str()).map(str::to_string));
+
+        let thumbnail =
+            &lockup["contentImage"]["collectionThumbnailViewModel"]["primaryThumbnail"]["thumbnailViewModel"];
+        let thumbnails = serde_json::from_value(
+            thumbnail["image"]
+                .get("sources")
+                .ok_or(Error::JsonParse("No thumbnails found".into()))?
+                .clone(),
+        )
+        .map_err(|e| Error::JsonParse(e.to_string()))?;
+
+        let video_count = thumbnail["overlays"].as_array().and_then(|overlays| {
+            overlays.iter().find_map(|x| {
+                x["thumbnailOverlayBadgeViewModel"]["thumbnailBadges"]
+                    .get(0)
+                    .and_then(|x| x["thumbnailBadgeViewModel"]["text"].as_str())
+                    .and_then(|x| parse_count(x, hl).ok())
+            })
+        });
+
+        // The lockup only knows which video clicking it starts at.
+        let first_video = lockup["rendererContext"]["commandContext"]["onTap"]["innertubeCommand"]
+            .get("watchEndpoint")
+            .and_then(|x| x["videoId"].as_str())
+            .map(|video_id| {
+                PlaylistVideoPreview {
+                    id:     video_id.to_string(),
+                    url:    format!("https://www.youtube.com/watch?v={video_id}&list={id}"),
+                    title:  None,
+                    length: None,
+                }
+            });
+
+        Ok(Self {
+            url: Self::new_url(&id),
+            id,
+            title,
+            channel,
+            channel_id,
+            video_count,
+            thumbnails,
+            first_video,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovieResult {
+    pub id:                  String,
+    /// The watch url of the movie
+    pub url:                 String,
+    pub title:               String,
+    /// Usually "YouTube Movies" or the distributor.
+    pub channel:             Option<String>,
+    pub thumbnails:          Vec<Thumbnail>,
+    /// The length as displayed on the thumbnail (e.g. "1:52:03").
+    pub length_text:         Option<String>,
+    /// [`MovieResult::length_text`] as a duration.
+    pub length:              Option<Duration>,
+    pub description_snippet: Option<String>,
+    /// Lines of extra information like "Action & Adventure • 2019" or "Actors: ...".
+    pub metadata:            Vec<String>,
+    /// The movie's offer as displayed (e.g. "Buy or rent" or "Free with ads").
+    pub offer:               Option<String>,
+    /// Badges like the age rating (e.g. `Badge::Other("PG-13")`).
+    pub badges:              Vec<Badge>,
+}
+
+impl MovieResult {
+    /// Parse a `movieRenderer`.
+    fn parse_movie(movie: &Value, hl: &str) -> Result<Self, Error> {
+        let id = movie["videoId"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or(Error::JsonParse("No movie id found".into()))?;
+
+        let title = crawl_object_for_string(&movie["title"], &["accessibility"])
+            .ok_or(Error::JsonParse("No title found".into()))?
+            .to_string();
+
+        let thumbnails = serde_json::from_value(
+            movie["thumbnail"]
+                .get("thumbnails")
+                .ok_or(Error::JsonParse("No thumbnails found".into()))?
+                .clone(),
+        )
+        .map_err(|e| Error::JsonParse(e.to_string()))?;
+
+        let url = parse_url(movie).ok_or_else(|| Error::JsonParse("No watch URL".into()))?;
+
+        let metadata = ["topMetadataItems", "bottomMetadataItems"]
+            .iter()
+            .filter_map(|key| movie[*key].as_array())
+            .flatten()
+            .filter_map(parse_text)
+            .collect();
+
+        let badges = Badge::parse_badges(&movie["badges"]);
+        let offer = movie["offerButton"]["buttonRenderer"]
+            .get("text")
+            .or_else(|| {
+                movie["buttons"]
+                    .as_array()?
+                    .iter()
+                    .find_map(|x| x["buttonRenderer"].get("text"))
+            })
+            .and_then(parse_text);
+
+        Ok(Self {
+            id,
+            url,
+            title,
+            channel: movie.get("longBylineText").and_then(parse_text),
+            thumbnails,
+            length_text: movie.get("lengthText").and_then(parse_text),
+            length: movie.get("lengthText").and_then(|x| parse_length(x, hl)),
+            description_snippet: movie.get("descriptionSnippet").and_then(parse_text),
+            metadata,
+            offer,
+            badges,
+        })
+    }
+}
+
+/// Mixes are playlists `YouTube` generates, their IDs start with "RD". Those starting
+/// with "RDCLAK" are curated playlists rather than mixes though.
+fn is_mix_id(id: &str) -> bool { id.starts_with("RD") && !id.starts_with("RDCLAK") }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixResult {
+    /// The playlist ID of the mix.
+    pub id:            String,
+    /// The watch url the mix starts playing from
+    pub url:           String,
+    pub title:         String,
+    /// The video the mix was generated from, if it was generated from a video.
+    pub seed_video_id: Option<String>,
+    pub thumbnails:    Vec<Thumbnail>,
+    /// The first few videos in the mix, not available from every kind of mix result.
+    pub videos:        Vec<PlaylistVideoPreview>,
+}
+
+impl MixResult {
+    fn new_url(id: &str, seed_video_id: Option<&str>) -> String {
+        match seed_video_id {
+            Some(video_id) => format!("https://www.youtube.com/watch?v={video_id}&list={id}"),
+            None => PlaylistResult::new_url(id),
+        }
+    }
+
+    /// Parse a `radioRenderer`.
+    fn parse_radio(radio: &Value, hl: &str) -> Result<Self, Error> {
+        let id = radio["playlistId"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or(Error::JsonParse("No mix id found".into()))?;
+
+        let title = crawl_object_for_string(&radio["title"], &["accessibility"])
+            .ok_or(Error::JsonParse("No title found".into()))?
+            .to_string();
+
+        let thumbnails = serde_json::from_value(
+            radio["thumbnail"]
+                .get("thumbnails")
+                .ok_or(Error::JsonParse("No thumbnails found".into()))?
+                .clone(),
+        )
+        .map_err(|e| Error::JsonParse(e.to_string()))?;
+
+        let seed_video_id = radio["navigationEndpoint"]["watchEndpoint"]["videoId"]
+            .as_str()
+            .map(str::to_string);
+
+        let videos = radio["videos"]
+            .as_array()
+            .map(|videos| {
+                videos
+                    .iter()
+                    .filter_map(|x| x.get("childVideoRenderer"))
+                    .filter_map(|x| PlaylistVideoPreview::parse_child(x, hl))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            url: Self::new_url(&id, seed_video_id.as_deref()),
+            id,
+            title,
+            seed_video_id,
+            thumbnails,
+            videos,
+        })
+    }
+
+    /// Parse a mix `lockupViewModel`.
+    fn parse_lockup(lockup: &Value, hl: &str) -> Result<Self, Error> {
+        // They're the same as playlists apart from the owner and video count being replaced
+        // by text like "Mix" or "Rick Astley, Queen, and more".
+        let PlaylistResult {
+            id,
+            title,
+            thumbnails,
+            first_video,
+            ..
+        } = PlaylistResult::parse_lockup(lockup, hl)?;
+
+        let seed_video_id = first_video.map(|x| x.id);
+
+        Ok(Self {
+            url: Self::new_url(&id, seed_video_id.as_deref()),
+            id,
+            title,
+            seed_video_id,
+            thumbnails,
+            videos: Vec::new(),
+        })
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use crate::{client::ClientBuilder, endpoints::search};
 
+    /// A trimmed down `videoRenderer` as found in a search response.
+    pub(crate) fn video_renderer() -> Value {
+        serde_json::json!({
+            "videoId": "dQw4w9WgXcQ",
+            "thumbnail": { "thumbnails": [{ "url": "https://i.ytimg.com/vi/dQw4w9WgXcQ/hq720.jpg", "width": 360, "height": 202 }] },
+            "title": {
+                "runs": [{ "text": "Rick Astley - Never Gonna Give You Up (Official Music Video)" }],
+                "accessibility": { "accessibilityData": { "label": "Rick Astley - Never Gonna Give You Up (Official Music Video) by Rick Astley 1,234,567,890 views 14 years ago 3 minutes, 33 seconds" } }
+            },
+            "longBylineText": { "runs": [{
+                "text": "Rick Astley",
+                "navigationEndpoint": { "browseEndpoint": { "browseId": "UCuAXFkgsw1L7xaCfnd5JJOw", "canonicalBaseUrl": "/@RickAstleyYT" } }
+            }] },
+            "publishedTimeText": { "simpleText": "14 years ago" },
+            "lengthText": {
+                "accessibility": { "accessibilityData": { "label": "3 minutes, 33 seconds" } },
+                "simpleText": "3:33"
+            },
+            "viewCountText": { "simpleText": "1,234,567,890 views" },
+            "navigationEndpoint": {
+                "commandMetadata": { "webCommandMetadata": { "url": "/watch?v=dQw4w9WgXcQ" } },
+                "watchEndpoint": { "videoId": "dQw4w9WgXcQ" }
+            },
+            "badges": [
+                { "metadataBadgeRenderer": { "style": "BADGE_STYLE_TYPE_SIMPLE", "label": "4K" } },
+                { "metadataBadgeRenderer": { "style": "BADGE_STYLE_TYPE_SIMPLE", "label": "CC" } }
+            ],
+            "ownerBadges": [{ "metadataBadgeRenderer": {
+                "icon": { "iconType": "OFFICIAL_ARTIST_BADGE" },
+                "style": "BADGE_STYLE_TYPE_VERIFIED_ARTIST",
+                "tooltip": "Official Artist Channel"
+            } }],
+            "ownerText": { "runs": [{ "text": "Rick Astley" }] },
+            "shortBylineText": { "runs": [{ "text": "Rick Astley" }] },
+            "shortViewCountText": { "simpleText": "1.2B views" },
+            "channelThumbnailSupportedRenderers": { "channelThumbnailWithLinkRenderer": {
+                "thumbnail": { "thumbnails": [{ "url": "https://yt3.ggpht.com/avatar", "width": 68, "height": 68 }] }
+            } },
+            "detailedMetadataSnippets": [{ "snippetText": { "runs": [
+                { "text": "The official video for " },
+                { "text": "Never Gonna Give You Up", "bold": true },
+                { "text": " by Rick Astley." }
+            ] } }]
+        })
+    }
+
+    #[test]
+    fn test_parse_video_metadata() {
+        let video = VideoResult::parse_video(&video_renderer(), "en").unwrap();
+        assert_eq!(video.id, "dQw4w9WgXcQ");
+        assert_eq!(video.url, "https://www.youtube.com/watch?v=dQw4w9WgXcQ");
+        assert_eq!(video.channel, "Rick Astley");
+        assert_eq!(video.channel_id.as_deref(), Some("UCuAXFkgsw1L7xaCfnd5JJOw"));
+        assert_eq!(video.channel_avatar[0].url, "https://yt3.ggpht.com/avatar");
+        assert_eq!(video.channel_badges, [Badge::VerifiedArtist]);
+        assert_eq!(video.view_count, Some(Count::exact(1_234_567_890)));
+        assert_eq!(video.length_text.as_deref(), Some("3:33"));
+        assert_eq!(video.length, Some(Duration::from_secs(213)));
+        assert_eq!(video.published_text.as_deref(), Some("14 years ago"));
+        assert!(video.published.is_some());
+        assert_eq!(
+            video.description_snippet.as_deref(),
+            Some("The official video for Never Gonna Give You Up by Rick Astley.")
+        );
+        assert_eq!(video.badges, [Badge::FourK, Badge::ClosedCaptions]);
+    }
+
+    pub(crate) fn short_renderer() -> Value {
+        serde_json::json!({
+            "videoId": "xxxxxxxxxxx",
+            "headline": { "simpleText": "Cat tips" },
+            "thumbnail": { "thumbnails": [{ "url": "https://i.ytimg.com/vi/xxxxxxxxxxx/frame0.jpg", "width": 405, "height": 720 }] },
+            "viewCountText": {
+                "simpleText": "12K views",
+                "accessibility": { "accessibilityData": { "label": "12,345 views" } }
+            },
+            "navigationEndpoint": {
+                "commandMetadata": { "webCommandMetadata": { "url": "/shorts/xxxxxxxxxxx" } },
+                "reelWatchEndpoint": { "videoId": "xxxxxxxxxxx" }
+            }
+        })
+    }
+
+    #[test]
+    fn test_parse_items_in_order() {
+        let mut second_video = video_renderer();
+        second_video["videoId"] = "yyyyyyyyyyy".into();
+        let contents = serde_json::json!([{ "itemSectionRenderer": { "contents": [
+            { "videoRenderer": video_renderer() },
+            { "reelShelfRenderer": {
+                "title": { "simpleText": "Shorts" },
+                "items": [{ "reelItemRenderer": short_renderer() }]
+            } },
+            { "shelfRenderer": {
+                "title": { "simpleText": "People also watched" },
+                "content": { "verticalListRenderer": { "items": [{ "videoRenderer": second_video }] } }
+            } },
+            { "channelRenderer": {
+                "channelId": "UCuAXFkgsw1L7xaCfnd5JJOw",
+                "title": { "simpleText": "Rick Astley" },
+                "navigationEndpoint": { "commandMetadata": { "webCommandMetadata": { "url": "/@RickAstleyYT" } } }
+            } }
+        ] } }]);
+
+        let mut results = SearchResults::default();
+        results.parse_contents(&contents, "$").unwrap();
+
+        assert_eq!(results.items.len(), 4);
+        assert!(matches!(&results.items[0], SearchItem::Video(x) if x.id == "dQw4w9WgXcQ"));
+        let SearchItem::Shelf { title, items } = &results.items[1] else {
+            panic!("Expected the shorts shelf");
+        };
+        assert_eq!(title.as_deref(), Some("Shorts"));
+        assert!(matches!(&items[..], [SearchItem::Short(x)] if x.id == "xxxxxxxxxxx"));
+        let SearchItem::Short(short) = &items[0] else {
+            unreachable!()
+        };
+        // The accessibility label has the exact count.
+        assert_eq!(short.view_count, Some(Count::exact(12_345)));
+        let SearchItem::Shelf { title, items } = &results.items[2] else {
+            panic!("Expected the people also watched shelf");
+        };
+        assert_eq!(title.as_deref(), Some("People also watched"));
+        assert!(matches!(&items[..], [SearchItem::Video(x)] if x.id == "yyyyyyyyyyy"));
+        assert!(matches!(&results.items[3], SearchItem::Channel(_)));
+
+        // Everything is still in the lists by kind.
+        let video_ids = results.videos.iter().map(|x| x.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(video_ids, ["dQw4w9WgXcQ", "yyyyyyyyyyy"]);
+        assert_eq!(results.shorts.len(), 1);
+        assert_eq!(results.channels.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_page() {
+        let page = |ids: &[&str]| {
+            let items = ids
+                .iter()
+                .map(|id| {
+                    let mut video = video_renderer();
+                    video["videoId"] = (*id).into();
+                    serde_json::json!({ "videoRenderer": video })
+                })
+                .collect::<Vec<_>>();
+            let mut results = SearchResults::default();
+            results.parse_contents(&Value::Array(items), "$").unwrap();
+            results
+        };
+        let video_ids =
+            |results: &SearchResults| results.videos.iter().map(|x| x.id.clone()).collect::<Vec<_>>();
+
+        // Duplicates on the same page are left out too.
+        let mut results = page(&["a", "b", "a"]);
+        assert_eq!(video_ids(&results), ["a", "b"]);
+        assert_eq!(results.items.len(), 2);
+
+        results.merge_page(page(&["b", "c"]), ContinuationMode::Accumulate);
+        assert_eq!(video_ids(&results), ["a", "b", "c"]);
+        assert_eq!(results.items.len(), 3);
+
+        // Duplicates from pages before the last are still left out when replacing.
+        results.merge_page(page(&["a", "d"]), ContinuationMode::Replace);
+        assert_eq!(video_ids(&results), ["d"]);
+        assert_eq!(results.items.len(), 1);
+
+        // A page of only channels still counts.
+        let mut channel = page(&[]);
+        channel
+            .parse_contents(&serde_json::json!([{ "channelRenderer": {
+                "channelId": "UCuAXFkgsw1L7xaCfnd5JJOw",
+                "title": { "simpleText": "Rick Astley" },
+                "navigationEndpoint": { "commandMetadata": { "webCommandMetadata": { "url": "/@RickAstleyYT" } } }
+            } }]), "$")
+            .unwrap();
+        results.merge_page(channel, ContinuationMode::Accumulate);
+        assert_eq!(results.channels.len(), 1);
+        assert_eq!(results.items.len(), 2);
+    }
+
+    /// A continuation response with `contents` as the page, and a continuation to the
+    /// next page if there's a `token`.
+    fn continuation_response(contents: Value, token: Option<&str>) -> Value {
+        let mut items = vec![serde_json::json!({ "itemSectionRenderer": { "contents": contents } })];
+        if let Some(token) = token {
+            items.push(
+                serde_json::json!({ "continuationItemRenderer": { "continuationEndpoint": {
+                "continuationCommand": { "token": token }
+            } } }),
+            );
+        }
+        serde_json::json!({
+            "estimatedResults": "100",
+            "onResponseReceivedCommands": [{ "appendContinuationItemsAction": { "continuationItems": items } }]
+        })
+    }
+
+    #[test]
+    fn test_continuation_page() {
+        let client = ClientBuilder::new().build().unwrap();
+        let ad = serde_json::json!({ "searchPyvRenderer": { "ads": [{ "promotedVideoRenderer": {
+            "videoId": "bbbbbbbbbbb",
+            "title": { "simpleText": "Buy our stuff" },
+            "navigationEndpoint": { "urlEndpoint": { "url": "https://example.com/stuff" } }
+        } }] } });
+
+        // Nothing but an ad, there's still another page after it.
+        let response = continuation_response(serde_json::json!([ad]), Some("next-page"));
+        let page = SearchResults::from_continuation_search_results(&response, &client)
+            .unwrap()
+            .unwrap();
+        assert!(page.items.is_empty());
+        assert_eq!(page.continuation, "next-page");
+
+        // The last page.
+        let response =
+            continuation_response(serde_json::json!([{ "videoRenderer": video_renderer() }]), None);
+        let mut results = SearchResults::default();
+        let page = SearchResults::from_continuation_search_results(&response, &client)
+            .unwrap()
+            .unwrap();
+        results.merge_page(page, ContinuationMode::Accumulate);
+        assert_eq!(results.videos.len(), 1);
+        assert_eq!(results.continuation, "");
+
+        // Nothing but a result that fails to parse.
+        let mut broken = video_renderer();
+        broken.as_object_mut().unwrap().remove("longBylineText");
+        broken.as_object_mut().unwrap().remove("ownerText");
+        broken.as_object_mut().unwrap().remove("shortBylineText");
+        let response = continuation_response(serde_json::json!([{ "videoRenderer": broken }]), None);
+        let page = SearchResults::from_continuation_search_results(&response, &client)
+            .unwrap()
+            .unwrap();
+        assert!(page.items.is_empty());
+        assert_eq!(page.parse_errors.len(), 1);
+
+        let response = continuation_response(serde_json::json!([]), None);
+        assert_eq!(
+            SearchResults::from_continuation_search_results(&response, &client).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let results = SearchResults {
+            continuation: "EpcDEg9saW51cyB0ZWNoIHRpcHM".into(),
+            query: "linus tech tips".into(),
+            params: Some("EgIQAQ%3D%3D".into()),
+            variant: ClientVariant::Mweb,
+            locale: Locale::from("de-DE"),
+            ..Default::default()
+        };
+
+        let cursor = results.cursor();
+        let json = serde_json::to_string(&cursor).unwrap();
+        let restored: SearchCursor = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, cursor);
+        assert_eq!(restored.continuation, "EpcDEg9saW51cyB0ZWNoIHRpcHM");
+        assert_eq!(restored.variant, ClientVariant::Mweb);
+        assert_eq!(restored.locale, Locale::from("de-DE"));
+
+        // Cursors from before the seen IDs were kept still load.
+        let mut old = serde_json::to_value(&cursor).unwrap();
+        old.as_object_mut().unwrap().remove("seen");
+        let old: SearchCursor = serde_json::from_value(old).unwrap();
+        assert!(old.seen.is_empty());
+    }
+
+    #[test]
+    fn test_resume_skips_seen() {
+        let client = ClientBuilder::new().build().unwrap();
+        let mut results = SearchResults {
+            query: "rick astley".into(),
+            ..Default::default()
+        };
+        let response = continuation_response(
+            serde_json::json!([{ "videoRenderer": video_renderer() }]),
+            Some("next-page"),
+        );
+        let page = SearchResults::from_continuation_search_results(&response, &client)
+            .unwrap()
+            .unwrap();
+        results.merge_page(page, ContinuationMode::Accumulate);
+
+        let json = serde_json::to_string(&results.cursor()).unwrap();
+        let cursor: SearchCursor = serde_json::from_str(&json).unwrap();
+        assert!(cursor.seen.contains("dQw4w9WgXcQ"));
+
+        let mut other = video_renderer();
+        other["videoId"] = "aaaaaaaaaaa".into();
+        let response = continuation_response(
+            serde_json::json!([{ "videoRenderer": video_renderer() }, { "videoRenderer": other }]),
+            None,
+        );
+        let page = SearchResults::from_continuation_search_results(&response, &client)
+            .unwrap()
+            .unwrap();
+        let resumed = SearchResults::from_cursor_page(&cursor, page);
+        assert_eq!(resumed.query, "rick astley");
+        assert_eq!(resumed.videos.len(), 1);
+        assert_eq!(resumed.videos[0].id, "aaaaaaaaaaa");
+        assert!(resumed.seen.contains("aaaaaaaaaaa"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let mut broken = video_renderer();
+        broken["videoId"] = "yyyyyyyyyyy".into();
+        broken.as_object_mut().unwrap().remove("longBylineText");
+        broken.as_object_mut().unwrap().remove("ownerText");
+        broken.as_object_mut().unwrap().remove("shortBylineText");
+        let contents = serde_json::json!([{ "itemSectionRenderer": { "contents": [
+            { "videoRenderer": broken },
+            { "videoRenderer": video_renderer() }
+        ] } }]);
+
+        let mut results = SearchResults::default();
+        results.parse_contents(&contents, "$.contents").unwrap();
+        assert_eq!(results.videos.len(), 1);
+        assert_eq!(results.videos[0].id, "dQw4w9WgXcQ");
+        let [error] = &results.parse_errors[..] else {
+            panic!("{:?}", results.parse_errors);
+        };
+        assert_eq!(
+            error.path,
+            "$.contents[0].itemSectionRenderer.contents[0].videoRenderer"
+        );
+        assert_eq!(error.value["videoId"], "yyyyyyyyyyy");
+        assert_eq!(error.message, "No channel found");
+
+        let mut results = SearchResults {
+            parse_mode: ParseMode::Strict,
+            ..Default::default()
+        };
+        let Err(Error::ItemParse(error)) = results.parse_contents(&contents, "$.contents") else {
+            panic!("Strict parsing should fail");
+        };
+        assert_eq!(error.message, "No channel found");
+    }
+
+    #[test]
+    fn test_parse_ads() {
+        let mut promoted_video = video_renderer();
+        promoted_video["videoId"] = "aaaaaaaaaaa".into();
+        let contents = serde_json::json!([{ "itemSectionRenderer": { "contents": [
+            { "adSlotRenderer": { "fulfillmentContent": { "fulfilledLayout": { "inFeedAdLayoutRenderer": {
+                "renderingContent": { "videoRenderer": promoted_video }
+            } } } } },
+            { "videoRenderer": video_renderer() },
+            { "searchPyvRenderer": { "ads": [
+                { "promotedVideoRenderer": {
+                    "videoId": "bbbbbbbbbbb",
+                    "thumbnail": { "thumbnails": [{ "url": "https://i.ytimg.com/vi/bbbbbbbbbbb/hqdefault.jpg" }] },
+                    "title": { "simpleText": "Buy our stuff" },
+                    "longBylineText": { "runs": [{ "text": "Stuff Inc." }] },
+                    "navigationEndpoint": {
+                        "urlEndpoint": { "url": "https://example.com/stuff" }
+                    }
+                } }
+            ] } }
+        ] } }]);
+
+        let mut results = SearchResults::default();
+        results.parse_contents(&contents, "$").unwrap();
+        assert_eq!(results.videos.len(), 1);
+        assert_eq!(results.videos[0].id, "dQw4w9WgXcQ");
+        assert_eq!(results.items.len(), 1);
+
+        assert_eq!(results.ads.len(), 2);
+        assert_eq!(results.ads[0].video_id.as_deref(), Some("aaaaaaaaaaa"));
+        assert_eq!(results.ads[0].video.as_ref().unwrap().id, "aaaaaaaaaaa");
+        assert_eq!(results.ads[1].video_id.as_deref(), Some("bbbbbbbbbbb"));
+        assert_eq!(results.ads[1].title.as_deref(), Some("Buy our stuff"));
+        assert_eq!(results.ads[1].url.as_deref(), Some("https://example.com/stuff"));
+        assert_eq!(results.ads[1].advertiser.as_deref(), Some("Stuff Inc."));
+        assert_eq!(results.ads[1].video, None);
+
+        // Ads don't count as seen, so the same video can still show up as a real result.
+        assert!(!results.seen.contains("aaaaaaaaaaa"));
+    }
+
+    #[test]
+    fn test_parse_movies_and_mixes() {
+        let contents = serde_json::json!([{ "itemSectionRenderer": { "contents": [
+            { "movieRenderer": {
+                "videoId": "mmmmmmmmmmm",
+                "thumbnail": { "thumbnails": [{ "url": "https://i.ytimg.com/vi/mmmmmmmmmmm/hqdefault.jpg" }] },
+                "title": { "runs": [{ "text": "Hackers" }] },
+                "descriptionSnippet": { "runs": [{ "text": "Hackers are blamed for making a virus." }] },
+                "longBylineText": { "runs": [{ "text": "YouTube Movies" }] },
+                "lengthText": { "simpleText": "1:45:02" },
+                "viewCountText": { "simpleText": "1,234 views" },
+                "navigationEndpoint": {
+                    "commandMetadata": { "webCommandMetadata": { "url": "/watch?v=mmmmmmmmmmm" } }
+                },
+                "topMetadataItems": [{ "simpleText": "Drama • 1995" }],
+                "bottomMetadataItems": [{ "runs": [{ "text": "Actors: " }, { "text": "Jonny Lee Miller" }] }],
+                "badges": [{ "metadataBadgeRenderer": { "style": "BADGE_STYLE_TYPE_SIMPLE", "label": "PG-13" } }],
+                "offerButton": { "buttonRenderer": { "text": { "runs": [{ "text": "Buy or rent" }] } } }
+            } },
+            { "radioRenderer": {
+                "playlistId": "RDdQw4w9WgXcQ",
+                "title": { "simpleText": "Mix - Rick Astley - Never Gonna Give You Up" },
+                "thumbnail": { "thumbnails": [{ "url": "https://i.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg" }] },
+                "navigationEndpoint": {
+                    "commandMetadata": { "webCommandMetadata": { "url": "/watch?v=dQw4w9WgXcQ&list=RDdQw4w9WgXcQ" } },
+                    "watchEndpoint": { "videoId": "dQw4w9WgXcQ", "playlistId": "RDdQw4w9WgXcQ" }
+                },
+                "videos": [{ "childVideoRenderer": {
+                    "title": { "simpleText": "Never Gonna Give You Up" },
+                    "navigationEndpoint": {
+                        "commandMetadata": { "webCommandMetadata": { "url": "/watch?v=dQw4w9WgXcQ&list=RDdQw4w9WgXcQ" } }
+                    },
+                    "videoId": "dQw4w9WgXcQ"
+                } }]
+            } },
+            { "lockupViewModel": {
+                "contentImage": { "collectionThumbnailViewModel": { "primaryThumbnail": { "thumbnailViewModel": {
+                    "image": { "sources": [{ "url": "https://i.ytimg.com/vi/yPYZpwSpKmA/hqdefault.jpg" }] }
+                } } } },
+                "metadata": { "lockupMetadataViewModel": {
+                    "title": { "content": "Mix - Rick Astley" },
+                    "metadata": { "contentMetadataViewModel": { "metadataRows": [
+                        { "metadataParts": [{ "text": { "content": "Rick Astley, Queen, and more" } }] }
+                    ] } }
+                } },
+                "contentId": "RDyPYZpwSpKmA",
+                "contentType": "LOCKUP_CONTENT_TYPE_PLAYLIST",
+                "rendererContext": { "commandContext": { "onTap": { "innertubeCommand": {
+                    "watchEndpoint": { "videoId": "yPYZpwSpKmA" }
+                } } } }
+            } }
+        ] } }]);
+
+        let mut results = SearchResults::default();
+        results.parse_contents(&contents, "$").unwrap();
+        assert!(results.videos.is_empty() && results.playlists.is_empty());
+
+        let movie = &results.movies[0];
+        assert_eq!(movie.id, "mmmmmmmmmmm");
+        assert_eq!(movie.title, "Hackers");
+        assert_eq!(movie.channel.as_deref(), Some("YouTube Movies"));
+        assert_eq!(movie.length_text.as_deref(), Some("1:45:02"));
+        assert_eq!(movie.length, Some(Duration::from_secs(6302)));
+        assert_eq!(movie.metadata, ["Drama • 1995", "Actors: Jonny Lee Miller"]);
+        assert_eq!(movie.offer.as_deref(), Some("Buy or rent"));
+        assert_eq!(movie.badges, [Badge::Other("PG-13".into())]);
+
+        assert_eq!(results.mixes.len(), 2);
+        let radio = &results.mixes[0];
+        assert_eq!(radio.id, "RDdQw4w9WgXcQ");
+        assert_eq!(radio.seed_video_id.as_deref(), Some("dQw4w9WgXcQ"));
+        assert_eq!(
+            radio.url,
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ&list=RDdQw4w9WgXcQ"
+        );
+        assert_eq!(radio.videos.len(), 1);
+        let lockup = &results.mixes[1];
+        assert_eq!(lockup.id, "RDyPYZpwSpKmA");
+        assert_eq!(lockup.seed_video_id.as_deref(), Some("yPYZpwSpKmA"));
+
+        assert!(matches!(
+            &results.items[..],
+            [SearchItem::Movie(_), SearchItem::Mix(_), SearchItem::Mix(_)]
+        ));
+    }
+
+    #[test]
+    fn test_parse_video_length() {
+        // Shorts in the video list only have their length in the thumbnail overlay's
+        // accessibility label.
+        let mut short = video_renderer();
+        short.as_object_mut().unwrap().remove("lengthText");
+        short["thumbnailOverlays"] = serde_json::json!([{ "thumbnailOverlayTimeStatusRenderer": {
+            "text": {
+                "runs": [{ "text": "SHORTS" }],
+                "accessibility": { "accessibilityData": { "label": "Shorts, 58 seconds" } }
+            },
+            "style": "SHORTS"
+        } }]);
+        let video = VideoResult::parse_video(&short, "en").unwrap();
+        assert_eq!(video.length, Some(Duration::from_secs(58)));
+
+        short["thumbnailOverlays"][0]["thumbnailOverlayTimeStatusRenderer"]["text"] =
+            serde_json::json!({ "simpleText": "SHORTS" });
+        let video = VideoResult::parse_video(&short, "en").unwrap();
+        assert_eq!(video.length, None);
+    }
+
+    #[test]
+    fn test_parse_live_status() {
+        let mut live = video_renderer();
+        live["viewCountText"] = serde_json::json!({ "runs": [{ "text": "1,234" }, { "text": " watching" }] });
+        live["badges"] = serde_json::json!([{ "metadataBadgeRenderer": {
+            "style": "BADGE_STYLE_TYPE_LIVE_NOW",
+            "label": "LIVE"
+        } }]);
+        live.as_object_mut().unwrap().remove("lengthText");
+        live.as_object_mut().unwrap().remove("publishedTimeText");
+        let video = VideoResult::parse_video(&live, "en").unwrap();
+        assert_eq!(
+            video.live_status,
+            LiveStatus::Live {
+                concurrent_viewers: Some(Count::exact(1234)),
+            }
+        );
+        assert_eq!(video.view_count, None);
+        assert_eq!(video.length, None);
+
+        let mut upcoming = video_renderer();
+        upcoming["upcomingEventData"] = serde_json::json!({
+            "startTime": "1700000000",
+            "upcomingEventText": { "runs": [{ "text": "Scheduled for " }, { "text": "DATE_PLACEHOLDER" }] }
+        });
+        upcoming.as_object_mut().unwrap().remove("lengthText");
+        upcoming.as_object_mut().unwrap().remove("viewCountText");
+        let scheduled_start = Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        let video = VideoResult::parse_video(&upcoming, "en").unwrap();
+        assert_eq!(video.live_status, LiveStatus::Upcoming { scheduled_start });
+
+        upcoming["upcomingEventData"]["upcomingEventText"] =
+            serde_json::json!({ "runs": [{ "text": "Premieres " }, { "text": "DATE_PLACEHOLDER" }] });
+        let video = VideoResult::parse_video(&upcoming, "en").unwrap();
+        assert_eq!(video.live_status, LiveStatus::Premiere { scheduled_start });
+
+        let video = VideoResult::parse_video(&video_renderer(), "en").unwrap();
+        assert_eq!(video.live_status, LiveStatus::None);
+    }
+
+    #[test]
+    fn test_parse_upcoming_without_view_count() {
+        let mut upcoming = video_renderer();
+        upcoming["videoId"] = "uuuuuuuuuuu".into();
+        upcoming["upcomingEventData"] = serde_json::json!({
+            "startTime": "1700000000",
+            "upcomingEventText": { "runs": [{ "text": "Scheduled for " }, { "text": "DATE_PLACEHOLDER" }] }
+        });
+        upcoming.as_object_mut().unwrap().remove("lengthText");
+        upcoming.as_object_mut().unwrap().remove("viewCountText");
+        let contents = serde_json::json!([{ "itemSectionRenderer": { "contents": [
+            { "videoRenderer": video_renderer() },
+            { "videoRenderer": upcoming }
+        ] } }]);
+
+        let mut results = SearchResults::default();
+        results.parse_contents(&contents, "$").unwrap();
+        let video_ids = results.videos.iter().map(|x| x.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(video_ids, ["dQw4w9WgXcQ", "uuuuuuuuuuu"]);
+        assert!(matches!(
+            results.videos[1].live_status,
+            LiveStatus::Upcoming { .. }
+        ));
+        assert_eq!(results.videos[1].view_count, None);
+        assert!(results.parse_errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_channel() {
+        let channel = serde_json::json!({
+            "channelId": "UCXuqSBlHAE6Xw-yeJA0Tunw",
+            "title": { "simpleText": "Linus Tech Tips" },
+            "navigationEndpoint": {
+                "commandMetadata": { "webCommandMetadata": { "url": "/@LinusTechTips" } },
+                "browseEndpoint": { "browseId": "UCXuqSBlHAE6Xw-yeJA0Tunw", "canonicalBaseUrl": "/@LinusTechTips" }
+            },
+            "thumbnail": { "thumbnails": [{ "url": "//yt3.googleusercontent.com/avatar", "width": 88, "height": 88 }] },
+            "descriptionSnippet": { "runs": [{ "text": "Linus Tech Tips is a passionate team of " }, { "text": "tech", "bold": true }] },
+            "subscriberCountText": { "simpleText": "@LinusTechTips" },
+            "videoCountText": { "simpleText": "15.6M subscribers" },
+            "ownerBadges": [{ "metadataBadgeRenderer": { "style": "BADGE_STYLE_TYPE_VERIFIED", "tooltip": "Verified" } }]
+        });
+
+        let channel = ChannelResult::parse_channel(&channel, "en").unwrap();
+        assert_eq!(channel.url, "https://www.youtube.com/@LinusTechTips");
+        assert_eq!(channel.handle.as_deref(), Some("@LinusTechTips"));
+        assert_eq!(channel.subscriber_count.map(|x| x.value), Some(15_600_000));
+        assert_eq!(channel.video_count, None);
+        assert_eq!(
+            channel.description_snippet.as_deref(),
+            Some("Linus Tech Tips is a passionate team of tech")
+        );
+        assert_eq!(channel.avatar[0].url, "https://yt3.googleusercontent.com/avatar");
+        assert_eq!(channel.badges, [Badge::Verified]);
+
+        // The older layout.
+        let channel = serde_json::json!({
+            "channelId": "UCXuqSBlHAE6Xw-yeJA0Tunw",
+            "title": { "simpleText": "Linus Tech Tips" },
+            "navigationEndpoint": {
+                "commandMetadata": { "webCommandMetadata": { "url": "/channel/UCXuqSBlHAE6Xw-yeJA0Tunw" } }
+            },
+            "subscriberCountText": { "simpleText": "15.6M subscribers" },
+            "videoCountText": { "runs": [{ "text": "6,789" }, { "text": " videos" }] }
+        });
+        let channel = ChannelResult::parse_channel(&channel, "en").unwrap();
+        assert_eq!(channel.handle, None);
+        assert_eq!(channel.subscriber_count.map(|x| x.value), Some(15_600_000));
+        assert_eq!(channel.video_count, Some(Count::exact(6789)));
+        assert!(channel.avatar.is_empty() && channel.badges.is_empty());
+    }
+
+    #[test]
+    fn test_parse_correction() {
+        let contents = serde_json::json!([{ "itemSectionRenderer": { "contents": [{ "showingResultsForRenderer": {
+            "showingResultsFor": { "runs": [{ "text": "Showing results for" }] },
+            "correctedQuery": { "runs": [{ "text": "linus ", "italics": true }, { "text": "tech", "bold": true, "italics": true }] },
+            "correctedQueryEndpoint": { "searchEndpoint": { "query": "linus tech" } },
+            "searchInsteadFor": { "runs": [{ "text": "Search instead for" }] },
+            "originalQuery": { "simpleText": "linus tecc" },
+            "originalQueryEndpoint": { "searchEndpoint": { "query": "linus tecc", "params": "QgIIAQ%3D%3D" } }
+        } }] } }]);
+        assert_eq!(
+            SearchCorrection::parse(&contents, "linus tecc"),
+            Some(SearchCorrection {
+                original_query:  "linus tecc".into(),
+                corrected_query: "linus tech".into(),
+                auto_corrected:  true,
+            })
+        );
+
+        let contents = serde_json::json!([{ "itemSectionRenderer": { "contents": [{ "didYouMeanRenderer": {
+            "didYouMean": { "runs": [{ "text": "Did you mean: " }] },
+            "correctedQuery": { "runs": [{ "text": "linus ", "italics": true }, { "text": "tech", "bold": true, "italics": true }] },
+            "correctedQueryEndpoint": { "searchEndpoint": { "query": "linus tech" } }
+        } }] } }]);
+        assert_eq!(
+            SearchCorrection::parse(&contents, "linus tecc"),
+            Some(SearchCorrection {
+                original_query:  "linus tecc".into(),
+                corrected_query: "linus tech".into(),
+                auto_corrected:  false,
+            })
+        );
+
+        assert_eq!(
+            SearchCorrection::parse(&serde_json::json!([]), "linus tech"),
+            None
+        );
+    }
+
     #[tokio::test]
     async fn test_crawl_search_results() {
         let client = ClientBuilder::new().build().unwrap();
@@ -468,7 +2208,7 @@ mod tests {
         let results = search(&client, "Linus Tech Tips", None).await.unwrap();
         let videos = crawl_for_objects_containing_keys(&results["contents"], VIDEO_JSON_KEYS, []);
         for video in videos {
-            let _ = VideoResult::parse_video(video).unwrap();
+            let _ = VideoResult::parse_video(video, "en").unwrap();
         }
     }
 
@@ -478,15 +2218,103 @@ mod tests {
         let results = search(&client, "Linus Tech Tips", None).await.unwrap();
         let videos = crawl_for_objects_containing_keys(&results["contents"], SHORT_JSON_KEYS, ["title"]);
         for video in videos {
-            let _ = ShortResult::parse_short(video).unwrap();
+            let _ = ShortResult::parse_short(video, "en").unwrap();
         }
     }
 
+    #[tokio::test]
+    async fn test_parse_playlists() {
+        let client = ClientBuilder::new().build().unwrap();
+        let results = search(&client, "Linus Tech Tips playlist", None).await.unwrap();
+        let results =
+            SearchResults::from_search_results(&results, "Linus Tech Tips playlist", None, &client).unwrap();
+        assert!(!results.playlists.is_empty());
+    }
+
+    #[test]
+    fn test_parse_playlist_renderer() {
+        let playlist = serde_json::json!({
+            "playlistId": "PL8mG-RkN2uTw7PhlnAr4pZZz2QubIbujH",
+            "title": { "simpleText": "Scrapyard Wars" },
+            "thumbnails": [{ "thumbnails": [{ "url": "https://i.ytimg.com/vi/a/hqdefault.jpg", "width": 480, "height": 270 }] }],
+            "videoCount": "1,024",
+            "navigationEndpoint": {
+                "commandMetadata": { "webCommandMetadata": { "url": "/watch?v=aaaaaaaaaaa&list=PL8mG-RkN2uTw7PhlnAr4pZZz2QubIbujH" } }
+            },
+            "shortBylineText": { "runs": [{
+                "text": "Linus Tech Tips",
+                "navigationEndpoint": { "browseEndpoint": { "browseId": "UCXuqSBlHAE6Xw-yeJA0Tunw" } }
+            }] },
+            "videos": [{ "childVideoRenderer": {
+                "title": { "simpleText": "Scrapyard Wars Episode 1" },
+                "lengthText": { "simpleText": "20:41" },
+                "navigationEndpoint": {
+                    "commandMetadata": { "webCommandMetadata": { "url": "/watch?v=aaaaaaaaaaa&list=PL8mG-RkN2uTw7PhlnAr4pZZz2QubIbujH" } }
+                },
+                "videoId": "aaaaaaaaaaa"
+            } }]
+        });
+
+        let playlist = PlaylistResult::parse_playlist(&playlist, "en").unwrap();
+        assert_eq!(playlist.id, "PL8mG-RkN2uTw7PhlnAr4pZZz2QubIbujH");
+        assert_eq!(playlist.title, "Scrapyard Wars");
+        assert_eq!(playlist.channel.as_deref(), Some("Linus Tech Tips"));
+        assert_eq!(playlist.channel_id.as_deref(), Some("UCXuqSBlHAE6Xw-yeJA0Tunw"));
+        assert_eq!(playlist.video_count, Some(Count::exact(1024)));
+        assert_eq!(playlist.thumbnails.len(), 1);
+        let first_video = playlist.first_video.unwrap();
+        assert_eq!(first_video.id, "aaaaaaaaaaa");
+        assert_eq!(first_video.title.as_deref(), Some("Scrapyard Wars Episode 1"));
+        assert_eq!(first_video.length, Some(Duration::from_secs(1241)));
+    }
+
+    #[test]
+    fn test_parse_playlist_lockup() {
+        let contents = serde_json::json!([{ "lockupViewModel": {
+            "contentImage": { "collectionThumbnailViewModel": { "primaryThumbnail": { "thumbnailViewModel": {
+                "image": { "sources": [{ "url": "https://i.ytimg.com/vi/b/hqdefault.jpg", "width": 480, "height": 270 }] },
+                "overlays": [{ "thumbnailOverlayBadgeViewModel": { "thumbnailBadges": [{
+                    "thumbnailBadgeViewModel": { "text": "12 videos" }
+                }] } }]
+            } } } },
+            "metadata": { "lockupMetadataViewModel": {
+                "title": { "content": "Tech Quickie" },
+                "metadata": { "contentMetadataViewModel": { "metadataRows": [
+                    { "metadataParts": [{ "text": {
+                        "content": "Techquickie",
+                        "commandRuns": [{ "onTap": { "innertubeCommand": {
+                            "browseEndpoint": { "browseId": "UC0vBXGSyV14uvJ4hECDOl0Q" }
+                        } } }]
+                    } }] },
+                    { "metadataParts": [{ "text": { "content": "View full playlist" } }] }
+                ] } }
+            } },
+            "contentId": "PLyqHHF2JVtY9EL-0aTbyr8K0Wl8nbCpXH",
+            "contentType": "LOCKUP_CONTENT_TYPE_PLAYLIST",
+            "rendererContext": { "commandContext": { "onTap": { "innertubeCommand": {
+                "watchEndpoint": { "videoId": "bbbbbbbbbbb" }
+            } } } }
+        } }]);
+
+        let mut results = SearchResults::default();
+        results.parse_contents(&contents, "$").unwrap();
+        assert!(results.videos.is_empty() && results.channels.is_empty());
+
+        let playlist = &results.playlists[0];
+        assert_eq!(playlist.id, "PLyqHHF2JVtY9EL-0aTbyr8K0Wl8nbCpXH");
+        assert_eq!(playlist.title, "Tech Quickie");
+        assert_eq!(playlist.channel.as_deref(), Some("Techquickie"));
+        assert_eq!(playlist.video_count, Some(Count::exact(12)));
+        assert_eq!(playlist.first_video.as_ref().unwrap().id, "bbbbbbbbbbb");
+    }
+
     #[tokio::test]
     async fn test_search_from_search_results() {
         let client = ClientBuilder::new().build().unwrap();
         let results = search(&client, "Linus Tech Tips", None).await.unwrap();
-        let videos = SearchResults::from_search_results(&results).unwrap().videos;
+        let videos = SearchResults::from_search_results(&results, "Linus Tech Tips", None, &client)
+            .unwrap()
+            .videos;
         assert!(!videos.is_empty());
     }
 
@@ -504,13 +2332,23 @@ mod tests {
         .unwrap();
         #[allow(unused_assignments)]
         let mut video_count = 0usize;
-        let mut results = SearchResults::from_search_results(&results).unwrap();
+        let mut results = SearchResults::from_search_results(
+            &results,
+            "How Much Memory for 1,000,000 Threads in 7 Languages",
+            None,
+            &client,
+        )
+        .unwrap();
         assert!(!results.videos.is_empty());
         video_count = results.videos.len();
         let inital_video_count = video_count;
 
         println!("Starting first continuation...");
-        while results.continue_search(&client).await.unwrap() {
+        while results
+            .continue_search(&client, ContinuationMode::Replace)
+            .await
+            .unwrap()
+        {
             assert!(!results.videos.is_empty());
             video_count += results.videos.len();
         }
@@ -0,0 +1,488 @@
+use std::str::FromStr;
+
+use base64::{
+    alphabet,
+    engine::{
+        general_purpose::{GeneralPurpose, GeneralPurposeConfig, STANDARD},
+        DecodePaddingMode,
+    },
+    Engine,
+};
+
+use crate::{
+    api::protobuf::{Field, Reader, Writer},
+    error::Error,
+};
+
+/// Copied parameters sometimes lose their padding, so don't be picky about it when
+/// decoding.
+const LENIENT: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Order the search results are returned in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortBy {
+    /// `YouTube`'s default ordering.
+    #[default]
+    Relevance  = 0,
+    Rating     = 1,
+    UploadDate = 2,
+    ViewCount  = 3,
+}
+
+impl SortBy {
+    fn from_value(value: u64) -> Option<Self> {
+        Some(match value {
+            0 => Self::Relevance,
+            1 => Self::Rating,
+            2 => Self::UploadDate,
+            3 => Self::ViewCount,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadDate {
+    LastHour  = 1,
+    Today     = 2,
+    ThisWeek  = 3,
+    ThisMonth = 4,
+    ThisYear  = 5,
+}
+
+impl UploadDate {
+    fn from_value(value: u64) -> Option<Self> {
+        Some(match value {
+            1 => Self::LastHour,
+            2 => Self::Today,
+            3 => Self::ThisWeek,
+            4 => Self::ThisMonth,
+            5 => Self::ThisYear,
+            _ => return None,
+        })
+    }
+}
+
+/// The kind of result to search for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultType {
+    Video    = 1,
+    Channel  = 2,
+    Playlist = 3,
+    Movie    = 4,
+}
+
+impl ResultType {
+    fn from_value(value: u64) -> Option<Self> {
+        Some(match value {
+            1 => Self::Video,
+            2 => Self::Channel,
+            3 => Self::Playlist,
+            4 => Self::Movie,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationFilter {
+    /// Under 4 minutes.
+    Short  = 1,
+    /// Over 20 minutes.
+    Long   = 2,
+    /// Between 4 and 20 minutes.
+    Medium = 3,
+}
+
+impl DurationFilter {
+    fn from_value(value: u64) -> Option<Self> {
+        Some(match value {
+            1 => Self::Short,
+            2 => Self::Long,
+            3 => Self::Medium,
+            _ => return None,
+        })
+    }
+}
+
+/// Features a result must have. The discriminant is the field number used in the
+/// protobuf message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Feature {
+    Hd              = 4,
+    Subtitles       = 5,
+    CreativeCommons = 6,
+    ThreeD          = 7,
+    Live            = 8,
+    Purchased       = 9,
+    FourK           = 14,
+    ThreeSixty      = 15,
+    Location        = 23,
+    Hdr             = 25,
+    Vr180           = 26,
+}
+
+impl Feature {
+    fn from_field(field: u32) -> Option<Self> {
+        Some(match field {
+            4 => Self::Hd,
+            5 => Self::Subtitles,
+            6 => Self::CreativeCommons,
+            7 => Self::ThreeD,
+            8 => Self::Live,
+            9 => Self::Purchased,
+            14 => Self::FourK,
+            15 => Self::ThreeSixty,
+            23 => Self::Location,
+            25 => Self::Hdr,
+            26 => Self::Vr180,
+            _ => return None,
+        })
+    }
+}
+
+/// A typed version of the `sp` URL parameter `YouTube` uses to filter and sort searches.
+///
+/// # Example
+///
+/// ```
+/// # use outertube::search::filter::{DurationFilter, ResultType, SearchFilters};
+/// let filters = SearchFilters::new()
+///     .result_type(ResultType::Video)
+///     .duration(DurationFilter::Medium);
+/// assert_eq!(filters.to_params(), "EgQQARgD");
+///
+/// // Parameters copied from the browser can be decoded too.
+/// let decoded: SearchFilters = "EgQQARgD".parse().unwrap();
+/// assert_eq!(decoded, filters);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchFilters {
+    sort_by:     SortBy,
+    upload_date: Option<UploadDate>,
+    result_type: Option<ResultType>,
+    duration:    Option<DurationFilter>,
+    /// Kept sorted by field number so the encoding is stable.
+    features:    Vec<Feature>,
+    exact_query: bool,
+}
+
+impl SearchFilters {
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    #[must_use]
+    pub fn sort_by(mut self, sort_by: SortBy) -> Self {
+        self.sort_by = sort_by;
+        self
+    }
+
+    #[must_use]
+    pub fn upload_date(mut self, upload_date: UploadDate) -> Self {
+        self.upload_date = Some(upload_date);
+        self
+    }
+
+    #[must_use]
+    pub fn result_type(mut self, result_type: ResultType) -> Self {
+        self.result_type = Some(result_type);
+        self
+    }
+
+    #[must_use]
+    pub fn duration(mut self, duration: DurationFilter) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Require results to have a feature. Can be called multiple times to require
+    /// several.
+    #[must_use]
+    pub fn feature(mut self, feature: Feature) -> Self {
+        if let Err(i) = self.features.binary_search(&feature) {
+            self.features.insert(i, feature);
+        }
+        self
+    }
+
+    /// Search for the query exactly as given instead of letting `YouTube` correct its
+    /// spelling. This is what the "Search instead for" link does.
+    #[must_use]
+    pub fn exact_query(mut self, exact_query: bool) -> Self {
+        self.exact_query = exact_query;
+        self
+    }
+
+    #[must_use]
+    pub fn get_sort_by(&self) -> SortBy { self.sort_by }
+
+    #[must_use]
+    pub fn get_upload_date(&self) -> Option<UploadDate> { self.upload_date }
+
+    #[must_use]
+    pub fn get_result_type(&self) -> Option<ResultType> { self.result_type }
+
+    #[must_use]
+    pub fn get_duration(&self) -> Option<DurationFilter> { self.duration }
+
+    #[must_use]
+    pub fn get_features(&self) -> &[Feature] { &self.features }
+
+    #[must_use]
+    pub fn get_exact_query(&self) -> bool { self.exact_query }
+
+    /// Encode the filters into the URL encoded `sp` parameter. An empty string is
+    /// returned when no filters are set, which `YouTube` treats the same as no
+    /// parameter at all.
+    #[must_use]
+    pub fn to_params(&self) -> String {
+        let mut filters = Writer::new();
+        if let Some(upload_date) = self.upload_date {
+            filters.varint(1, upload_date as u64);
+        }
+        if let Some(result_type) = self.result_type {
+            filters.varint(2, result_type as u64);
+        }
+        if let Some(duration) = self.duration {
+            filters.varint(3, duration as u64);
+        }
+        for feature in &self.features {
+            filters.varint(*feature as u32, 1);
+        }
+
+        let mut params = Writer::new();
+        if self.sort_by != SortBy::Relevance {
+            params.varint(1, self.sort_by as u64);
+        }
+        if !filters.is_empty() {
+            params.bytes(2, &filters.into_bytes());
+        }
+        if self.exact_query {
+            let mut query = Writer::new();
+            query.varint(1, 1);
+            params.bytes(8, &query.into_bytes());
+        }
+
+        if params.is_empty() {
+            return String::new();
+        }
+
+        urlencoding::encode(&STANDARD.encode(params.into_bytes())).into_owned()
+    }
+
+    /// Decode an `sp` parameter, URL encoded or not, into filters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSearchParams`] if the parameter isn't valid base64 or
+    /// protobuf, or if it contains fields or values that aren't understood.
+    pub fn from_params(params: &str) -> Result<Self, Error> {
+        let params = params.trim();
+        let mut filters = Self::new();
+        if params.is_empty() {
+            return Ok(filters);
+        }
+
+        let decoded = urlencoding::decode(params).map_err(|e| Error::InvalidSearchParams(e.to_string()))?;
+        let bytes = LENIENT
+            .decode(decoded.as_bytes())
+            .map_err(|e| Error::InvalidSearchParams(format!("\"{params}\" isn't valid base64: {e}")))?;
+
+        for field in Reader::new(&bytes) {
+            match field.map_err(Error::InvalidSearchParams)? {
+                (1, Field::Varint(value)) => {
+                    filters.sort_by =
+                        SortBy::from_value(value).ok_or_else(|| unknown_value("sort order", value))?;
+                }
+                (2, Field::Bytes(bytes)) => filters.decode_filters(bytes)?,
+                (8, Field::Bytes(bytes)) => filters.decode_query_options(bytes)?,
+                (field, _) => {
+                    return Err(Error::InvalidSearchParams(format!(
+                        "Unknown or malformed field {field}"
+                    )))
+                }
+            }
+        }
+
+        Ok(filters)
+    }
+
+    fn decode_filters(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        for field in Reader::new(bytes) {
+            let (field, value) = field.map_err(Error::InvalidSearchParams)?;
+            let Field::Varint(value) = value else {
+                return Err(Error::InvalidSearchParams(format!(
+                    "Filter field {field} isn't a varint"
+                )));
+            };
+
+            match field {
+                1 => {
+                    self.upload_date = Some(
+                        UploadDate::from_value(value).ok_or_else(|| unknown_value("upload date", value))?,
+                    );
+                }
+                2 => {
+                    self.result_type = Some(
+                        ResultType::from_value(value).ok_or_else(|| unknown_value("result type", value))?,
+                    );
+                }
+                3 => {
+                    self.duration = Some(
+                        DurationFilter::from_value(value).ok_or_else(|| unknown_value("duration", value))?,
+                    );
+                }
+                _ => {
+                    let feature = Feature::from_field(field)
+                        .ok_or_else(|| Error::InvalidSearchParams(format!("Unknown filter field {field}")))?;
+                    match value {
+                        0 => {}
+                        1 => *self = std::mem::take(self).feature(feature),
+                        _ => return Err(unknown_value("feature flag", value)),
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn decode_query_options(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        for field in Reader::new(bytes) {
+            match field.map_err(Error::InvalidSearchParams)? {
+                (1, Field::Varint(value @ (0 | 1))) => self.exact_query = value == 1,
+                (field, _) => {
+                    return Err(Error::InvalidSearchParams(format!(
+                        "Unknown or malformed query option field {field}"
+                    )))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for SearchFilters {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> { Self::from_params(s) }
+}
+
+fn unknown_value(name: &str, value: u64) -> Error {
+    Error::InvalidSearchParams(format!("Unknown {name} value {value}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_filters() {
+        assert_eq!(SearchFilters::new().to_params(), "");
+    }
+
+    #[test]
+    fn test_known_params() {
+        let filters = SearchFilters::new().result_type(ResultType::Video);
+        assert_eq!(filters.to_params(), "EgIQAQ%3D%3D");
+
+        let filters = SearchFilters::new().sort_by(SortBy::UploadDate);
+        assert_eq!(filters.to_params(), "CAI%3D");
+
+        let filters = SearchFilters::new()
+            .result_type(ResultType::Video)
+            .duration(DurationFilter::Medium);
+        assert_eq!(filters.to_params(), "EgQQARgD");
+    }
+
+    #[test]
+    fn test_feature_order_is_stable() {
+        let a = SearchFilters::new()
+            .feature(Feature::FourK)
+            .feature(Feature::Live);
+        let b = SearchFilters::new()
+            .feature(Feature::Live)
+            .feature(Feature::FourK)
+            .feature(Feature::Live);
+        assert_eq!(a, b);
+        assert_eq!(a.get_features(), [Feature::Live, Feature::FourK]);
+        assert_eq!(a.to_params(), b.to_params());
+    }
+
+    #[test]
+    fn test_decode_known_params() {
+        let filters = SearchFilters::from_params("EgQQARgD").unwrap();
+        assert_eq!(filters.get_result_type(), Some(ResultType::Video));
+        assert_eq!(filters.get_duration(), Some(DurationFilter::Medium));
+
+        // URL encoded, decoded, and with the padding stripped all mean the same thing.
+        let expected = SearchFilters::new().result_type(ResultType::Video);
+        for params in ["EgIQAQ%3D%3D", "EgIQAQ==", "EgIQAQ"] {
+            assert_eq!(SearchFilters::from_params(params).unwrap(), expected);
+        }
+
+        // The "Search instead for" link.
+        let filters = SearchFilters::from_params("QgIIAQ%3D%3D").unwrap();
+        assert_eq!(filters, SearchFilters::new().exact_query(true));
+        assert_eq!(filters.to_params(), "QgIIAQ%3D%3D");
+
+        // An explicit relevance sort is the same as no sort.
+        assert_eq!(SearchFilters::from_params("CAASAhAB").unwrap(), expected);
+        assert_eq!(SearchFilters::from_params("").unwrap(), SearchFilters::new());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let all = [
+            SearchFilters::new().sort_by(SortBy::ViewCount),
+            SearchFilters::new().upload_date(UploadDate::ThisYear),
+            SearchFilters::new()
+                .sort_by(SortBy::Rating)
+                .upload_date(UploadDate::LastHour)
+                .result_type(ResultType::Movie)
+                .duration(DurationFilter::Long)
+                .feature(Feature::Purchased)
+                .feature(Feature::Vr180)
+                .exact_query(true),
+            SearchFilters::new()
+                .result_type(ResultType::Video)
+                .feature(Feature::Hd)
+                .feature(Feature::Subtitles)
+                .feature(Feature::CreativeCommons)
+                .feature(Feature::ThreeD)
+                .feature(Feature::Live)
+                .feature(Feature::FourK)
+                .feature(Feature::ThreeSixty)
+                .feature(Feature::Location)
+                .feature(Feature::Hdr),
+        ];
+
+        for filters in all {
+            let params = filters.to_params();
+            let decoded: SearchFilters = params.parse().unwrap();
+            assert_eq!(decoded, filters, "{params}");
+            assert_eq!(decoded.to_params(), params);
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown() {
+        // Not base64.
+        assert!(matches!(
+            SearchFilters::from_params("!!!"),
+            Err(Error::InvalidSearchParams(_))
+        ));
+        // Unknown top level field 30.
+        assert!(SearchFilters::from_params("EgIQAfABAQ%3D%3D").is_err());
+        // Unknown filter field 10.
+        assert!(SearchFilters::from_params("EgJQAQ%3D%3D").is_err());
+        // Upload date out of range.
+        assert!(SearchFilters::from_params("EgIICQ%3D%3D").is_err());
+        // Truncated message.
+        assert!(SearchFilters::from_params("EgQQAQ%3D%3D").is_err());
+    }
+}
@@ -0,0 +1,212 @@
+use serde_json::Value;
+
+use crate::{api::parse_text, client::Client, endpoints, error::Error, search::Thumbnail};
+
+/// Get the suggested completions for a partial query, ranked the way `YouTube` ranked
+/// them.
+pub(crate) async fn search_suggestions(client: &Client, prefix: &str) -> Result<Vec<String>, Error> {
+    parse_suggestions(&endpoints::search_suggestions(client, prefix).await?)
+}
+
+/// The response is JSONP along the lines of
+/// `window.google.ac.h(["query",[["suggestion",0,[512]],...],{...}])`.
+/// Plain JSON is taken as is, as suggestions can have parentheses of their own.
+fn parse_suggestions(response: &str) -> Result<Vec<String>, Error> {
+    let response = response.trim();
+    let json = if response.starts_with(['[', '{']) {
+        response
+    } else {
+        // The function name has no parentheses in it, so the first one opens the call.
+        response
+            .find('(')
+            .zip(response.rfind(')'))
+            .and_then(|(start, end)| response.get(start + 1..end))
+            .unwrap_or(response)
+    };
+
+    let json = serde_json::from_str::<Value>(json).map_err(|e| Error::JsonParse(e.to_string()))?;
+    let suggestions = json
+        .get(1)
+        .and_then(Value::as_array)
+        .ok_or(Error::JsonParse("No suggestions found".into()))?;
+
+    Ok(suggestions
+        .iter()
+        .filter_map(|x| x.get(0).unwrap_or(x).as_str())
+        .map(str::to_string)
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MusicSearchSuggestions {
+    /// Suggested queries, in the order `YouTube` ranked them.
+    pub queries: Vec<String>,
+    /// Songs, artists, albums, etc. that `YouTube` thinks are being searched for.
+    pub results: Vec<MusicSuggestedResult>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MusicSuggestedResult {
+    pub title:      String,
+    /// Text under the title (e.g. "Song • Rick Astley • Whenever You Need Somebody").
+    pub subtitle:   Option<String>,
+    /// Set for songs and videos.
+    pub video_id:   Option<String>,
+    /// Set for artists, albums, and playlists.
+    pub browse_id:  Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+impl MusicSearchSuggestions {
+    pub(crate) async fn get(client: &Client, prefix: &str) -> Result<Self, Error> {
+        Self::from_response(&endpoints::music_search_suggestions(client, prefix).await?)
+    }
+
+    fn from_response(response: &Value) -> Result<Self, Error> {
+        let sections = response
+            .get("contents")
+            .and_then(Value::as_array)
+            .ok_or(Error::JsonParse("No 'contents' found".into()))?;
+
+        let mut suggestions = Self {
+            queries: Vec::new(),
+            results: Vec::new(),
+        };
+
+        let items = sections
+            .iter()
+            .filter_map(|x| x["searchSuggestionsSectionRenderer"]["contents"].as_array())
+            .flatten();
+        for item in items {
+            if let Some(suggestion) = item
+                .get("searchSuggestionRenderer")
+                .or_else(|| item.get("historySuggestionRenderer"))
+            {
+                let query = suggestion["navigationEndpoint"]["searchEndpoint"]["query"]
+                    .as_str()
+                    .map(str::to_string)
+                    .or_else(|| parse_text(&suggestion["suggestion"]))
+                    .ok_or(Error::JsonParse("No suggested query found".into()))?;
+                suggestions.queries.push(query);
+            } else if let Some(result) = item.get("musicResponsiveListItemRenderer") {
+                suggestions.results.push(MusicSuggestedResult::parse(result)?);
+            }
+        }
+
+        Ok(suggestions)
+    }
+}
+
+impl MusicSuggestedResult {
+    fn parse(result: &Value) -> Result<Self, Error> {
+        let column = |i: usize| {
+            result["flexColumns"]
+                .get(i)
+                .and_then(|x| parse_text(&x["musicResponsiveListItemFlexColumnRenderer"]["text"]))
+        };
+
+        let title = column(0).ok_or(Error::JsonParse("No title found".into()))?;
+        let endpoint = &result["navigationEndpoint"];
+        let video_id = endpoint["watchEndpoint"]["videoId"]
+            .as_str()
+            .or_else(|| result["playlistItemData"]["videoId"].as_str())
+            .map(str::to_string);
+        let browse_id = endpoint["browseEndpoint"]["browseId"]
+            .as_str()
+            .map(str::to_string);
+
+        let thumbnails = result["thumbnail"]["musicThumbnailRenderer"]["thumbnail"]
+            .get("thumbnails")
+            .and_then(|x| serde_json::from_value(x.clone()).ok())
+            .unwrap_or_default();
+
+        Ok(Self {
+            title,
+            subtitle: column(1),
+            video_id,
+            browse_id,
+            thumbnails,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_suggestions() {
+        let response = r#"window.google.ac.h(["never gonna",[["never gonna give you up",0,[512,433]],["never gonna let you go",0,[512]]],{"k":1,"q":"abc"}])"#;
+        assert_eq!(
+            parse_suggestions(response).unwrap(),
+            ["never gonna give you up", "never gonna let you go"]
+        );
+
+        // Plain JSON works too.
+        let response = r#"["never gonna",["never gonna give you up"]]"#;
+        assert_eq!(parse_suggestions(response).unwrap(), ["never gonna give you up"]);
+
+        // Parentheses in the query and suggestions.
+        let response = r#"window.google.ac.h(["rick (live",[["rick (live) astley",0,[512]],["rick astley (live)",0,[512]]],{"k":1}])"#;
+        assert_eq!(
+            parse_suggestions(response).unwrap(),
+            ["rick (live) astley", "rick astley (live)"]
+        );
+        let response = r#"["(rick",["(rick) astley","rick astley :)"]]"#;
+        assert_eq!(
+            parse_suggestions(response).unwrap(),
+            ["(rick) astley", "rick astley :)"]
+        );
+
+        assert!(parse_suggestions("<html>").is_err());
+    }
+
+    #[test]
+    fn test_parse_music_suggestions() {
+        let response = json!({ "contents": [
+            { "searchSuggestionsSectionRenderer": { "contents": [
+                { "searchSuggestionRenderer": {
+                    "suggestion": { "runs": [{ "text": "never gonna " }, { "text": "give you up", "bold": true }] },
+                    "navigationEndpoint": { "searchEndpoint": { "query": "never gonna give you up" } }
+                } }
+            ] } },
+            { "searchSuggestionsSectionRenderer": { "contents": [
+                { "musicResponsiveListItemRenderer": {
+                    "thumbnail": { "musicThumbnailRenderer": { "thumbnail": { "thumbnails": [
+                        { "url": "https://lh3.googleusercontent.com/a", "width": 60, "height": 60 }
+                    ] } } },
+                    "flexColumns": [
+                        { "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": [{ "text": "Never Gonna Give You Up" }] } } },
+                        { "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": [
+                            { "text": "Song" }, { "text": " • " }, { "text": "Rick Astley" }
+                        ] } } }
+                    ],
+                    "navigationEndpoint": { "watchEndpoint": { "videoId": "lYBUbBu4W08" } }
+                } },
+                { "musicResponsiveListItemRenderer": {
+                    "flexColumns": [
+                        { "musicResponsiveListItemFlexColumnRenderer": { "text": { "runs": [{ "text": "Rick Astley" }] } } }
+                    ],
+                    "navigationEndpoint": { "browseEndpoint": { "browseId": "UCuAXFkgsw1L7xaCfnd5JJOw" } }
+                } }
+            ] } }
+        ] });
+
+        let suggestions = MusicSearchSuggestions::from_response(&response).unwrap();
+        assert_eq!(suggestions.queries, ["never gonna give you up"]);
+        assert_eq!(suggestions.results.len(), 2);
+        assert_eq!(suggestions.results[0].title, "Never Gonna Give You Up");
+        assert_eq!(
+            suggestions.results[0].subtitle.as_deref(),
+            Some("Song • Rick Astley")
+        );
+        assert_eq!(suggestions.results[0].video_id.as_deref(), Some("lYBUbBu4W08"));
+        assert_eq!(
+            suggestions.results[1].browse_id.as_deref(),
+            Some("UCuAXFkgsw1L7xaCfnd5JJOw")
+        );
+        assert_eq!(suggestions.results[1].subtitle, None);
+    }
+}
@@ -0,0 +1,393 @@
+//! Transforming the `n` parameter of format URLs. `YouTube` throttles downloads to
+//! around 50 KB/s unless `n` has been run through a function in the player's `base.js`.
+//!
+//! Unlike the signature transforms the `n` function is a real, heavily obfuscated
+//! program that changes with every player, so it has to be run by a JavaScript engine.
+//! Which one is up to the caller, see [`JsEvaluator`].
+
+use regex::{Captures, Regex};
+
+use crate::error::Error;
+
+/// Where the `n` function is called when the player builds a URL:
+/// `.get("n"))&&(b=oma[0](b)` or `(b=String.fromCharCode(110),c=a.get(b))&&(c=Bza(c)`.
+/// The function is either called by name or through an array it's the only thing in.
+/// The locals have different names in every player, and `regex` has no back-references
+/// to match them up, so [`is_n_call`] checks they're the same.
+const N_CALL_PATTERN: &str = r#"(?:\.get\("n"\)\)&&\(([\w$]+)=|([\w$]+)=String\.fromCharCode\(110\),([\w$]+)=[\w$]+\.get\(([\w$]+)\)\)&&\(([\w$]+)=)([\w$]+)(?:\[(\d+)\])?\(([\w$]+)\)"#;
+/// A check some players put at the start of the `n` function that a global from
+/// elsewhere in the player is defined, which it won't be when the function is run on
+/// its own.
+const GLOBAL_CHECK_PATTERN: &str = r#"([{;])\s*if\s*\(\s*typeof\s+[\w$]+\s*===?\s*(?:"undefined"|'undefined'|[\w$]+\[\d+\])\s*\)\s*return\s+[\w$]+;"#;
+/// What the `n` function returns when it throws, followed by the input.
+const EXCEPTION_PREFIX: &str = "enhanced_except_";
+
+/// Runs JavaScript for [`NTransform`]. The crate doesn't come with a JavaScript engine,
+/// so this has to be implemented with one (e.g. QuickJS, Boa, or a Node process).
+///
+/// # Example
+///
+/// ```
+/// use outertube::{error::Error, throttling::JsEvaluator};
+///
+/// struct QuickJs;
+///
+/// impl JsEvaluator for QuickJs {
+///     fn call(&self, source: &str, function: &str, argument: &str) -> Result<String, Error> {
+///         let error = |e: rquickjs::Error| Error::JsEvaluation(e.to_string());
+///         let runtime = rquickjs::Runtime::new().map_err(error)?;
+///         let context = rquickjs::Context::full(&runtime).map_err(error)?;
+///         context
+///             .with(|ctx| {
+///                 ctx.eval::<(), _>(source)?;
+///                 let function: rquickjs::Function = ctx.globals().get(function)?;
+///                 function.call::<_, String>((argument,))
+///             })
+///             .map_err(error)
+///     }
+/// }
+/// ```
+pub trait JsEvaluator {
+    /// Run `source`, then call the global function `function` it defines with `argument`
+    /// and return the string it returns.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::JsEvaluation`] if the engine fails to run it.
+    fn call(&self, source: &str, function: &str, argument: &str) -> Result<String, Error>;
+}
+
+/// The `n` function of a player, extracted from its `base.js`. Players change every few
+/// weeks, so this should be made again for every new player.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NTransform {
+    name:   String,
+    /// The function on its own, as `var <name>=function(a){...};`.
+    source: String,
+}
+
+impl NTransform {
+    /// Extract the `n` function from the source of a player's `base.js`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::NTransform`] if the function can't be found, which means `YouTube`
+    /// changed how the player is written.
+    pub fn from_player(player_js: &str) -> Result<Self, Error> {
+        let pattern = |x: &str| Regex::new(x).expect("n transform patterns are valid");
+
+        let call = pattern(N_CALL_PATTERN)
+            .captures_iter(player_js)
+            .find(is_n_call)
+            .ok_or_else(|| Error::NTransform("the n function call wasn't found in the player".into()))?;
+        let mut name = call[6].to_string();
+
+        // `var oma=[Yxa];`
+        if let Some(index) = call.get(7) {
+            let index = index.as_str().parse::<usize>().unwrap_or_default();
+            let array = pattern(&format!(r"var {}\s*=\s*\[(.+?)\]", regex::escape(&name)))
+                .captures(player_js)
+                .ok_or_else(|| Error::NTransform(format!("the array '{name}' wasn't found in the player")))?;
+            name = array[1]
+                .split(',')
+                .nth(index)
+                .map(|x| x.trim().to_string())
+                .ok_or_else(|| Error::NTransform(format!("the array '{name}' is too short")))?;
+        }
+
+        let definition = pattern(&format!(
+            r"(?:function\s+{0}|[{{;,\s]{0}\s*=\s*function|var\s+{0}\s*=\s*function)\s*\(([\w$]*)\)\s*\{{",
+            regex::escape(&name)
+        ))
+        .captures(player_js)
+        .ok_or_else(|| Error::NTransform(format!("the n function '{name}' wasn't found in the player")))?;
+        let parameter = &definition[1];
+        let body_start = definition.get(0).map_or(0, |x| x.end() - 1);
+        let body_end = find_closing_brace(player_js, body_start)
+            .ok_or_else(|| Error::NTransform(format!("the end of the n function '{name}' wasn't found")))?;
+
+        let body = pattern(GLOBAL_CHECK_PATTERN).replace_all(&player_js[body_start..=body_end], "$1");
+        let source = format!("var {name}=function({parameter}){body};");
+
+        Ok(Self { name, source })
+    }
+
+    /// Transform an `n` parameter.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::JsEvaluation`] if `evaluator` fails, or [`Error::NTransform`] if the
+    /// function threw.
+    pub fn transform(&self, n: &str, evaluator: &dyn JsEvaluator) -> Result<String, Error> {
+        let transformed = evaluator.call(&self.source, &self.name, n)?;
+        if transformed.starts_with(EXCEPTION_PREFIX) || transformed.is_empty() {
+            return Err(Error::NTransform(format!(
+                "the n function failed on \"{n}\", returning \"{transformed}\""
+            )));
+        }
+
+        Ok(transformed)
+    }
+
+    /// Transform the `n` parameter of a format URL. URLs without one are left alone.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`NTransform::transform`].
+    pub fn transform_url(&self, url: &str, evaluator: &dyn JsEvaluator) -> Result<String, Error> {
+        let Some((base, query)) = url.split_once('?') else {
+            return Ok(url.to_string());
+        };
+
+        let mut parameters = Vec::new();
+        for parameter in query.split('&') {
+            match parameter.strip_prefix("n=") {
+                Some(n) => {
+                    let n = urlencoding::decode(n)
+                        .map_err(|e| Error::NTransform(format!("the n parameter isn't valid UTF-8: {e}")))?;
+                    let n = self.transform(&n, evaluator)?;
+                    parameters.push(format!("n={}", urlencoding::encode(&n)));
+                }
+                None => parameters.push(parameter.to_string()),
+            }
+        }
+
+        Ok(format!("{base}?{}", parameters.join("&")))
+    }
+}
+
+/// Whether a match of [`N_CALL_PATTERN`] passes `n` to the function and keeps what it
+/// returns in the same local: `(b=a.get("n"))&&(b=oma[0](b)`, or `b` being "n" and `c`
+/// its value in `(b=String.fromCharCode(110),c=a.get(b))&&(c=Bza(c)`.
+fn is_n_call(call: &Captures) -> bool {
+    let local = |i| call.get(i).map(|x| x.as_str());
+    match local(1) {
+        Some(value) => local(8) == Some(value),
+        None => local(2) == local(4) && local(3) == local(5) && local(3) == local(8),
+    }
+}
+
+/// Find the `}` that closes the `{` at `open`, skipping over strings, comments, and
+/// regular expressions, which the `n` function has plenty of.
+fn find_closing_brace(js: &str, open: usize) -> Option<usize> {
+    let bytes = js.as_bytes();
+    let mut depth = 0usize;
+    // The last byte that wasn't whitespace, to tell a regular expression from a division.
+    let mut previous = b'{';
+    let mut i = open;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        match byte {
+            b'{' => depth += 1,
+            b'}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            b'"' | b'\'' | b'`' => {
+                i += 1;
+                while *bytes.get(i)? != byte {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i += js[i..].find('\n')?;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += js[i..].find("*/")? + 1;
+            }
+            b'/' if b"(,=:[!&|?{};+-*%~^<>".contains(&previous) => {
+                let mut in_class = false;
+                i += 1;
+                loop {
+                    match *bytes.get(i)? {
+                        b'\\' => i += 1,
+                        b'[' => in_class = true,
+                        b']' => in_class = false,
+                        b'/' if !in_class => break,
+                        _ => {}
+                    }
+                    i += 1;
+                }
+            }
+            _ => {}
+        }
+
+        if !byte.is_ascii_whitespace() {
+            previous = bytes[i];
+        }
+        i += 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These players are synthetic, see the comment at the top of them. The expected
+    // outputs were worked out by running them with Node. `test_real_players` checks
+    // snapshots of real ones and `test_current_player` the one in use right now.
+    const PLAYER_A: &str = include_str!("../../test_data/players/synthetic_n_a.js");
+    const PLAYER_B: &str = include_str!("../../test_data/players/synthetic_n_b.js");
+
+    struct QuickJs;
+
+    impl JsEvaluator for QuickJs {
+        fn call(&self, source: &str, function: &str, argument: &str) -> Result<String, Error> {
+            let error = |e: rquickjs::Error| Error::JsEvaluation(e.to_string());
+            let runtime = rquickjs::Runtime::new().map_err(error)?;
+            let context = rquickjs::Context::full(&runtime).map_err(error)?;
+            context
+                .with(|ctx| {
+                    ctx.eval::<(), _>(source)?;
+                    let function: rquickjs::Function = ctx.globals().get(function)?;
+                    function.call::<_, String>((argument,))
+                })
+                .map_err(error)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_current_player() {
+        let player = crate::cipher::tests::current_player().await;
+        let transform = NTransform::from_player(&player).unwrap();
+        let n = transform.transform("kzGjXbJFAe5SfE0G", &QuickJs).unwrap();
+        assert_ne!(n, "kzGjXbJFAe5SfE0G");
+    }
+
+    #[test]
+    fn test_real_players() {
+        use crate::cipher::tests::{real_players, recorded_pairs};
+
+        for (id, player, pairs) in real_players() {
+            let transform = NTransform::from_player(&player).unwrap_or_else(|e| panic!("Player {id}: {e}"));
+            for (n, expected) in recorded_pairs(&pairs, "n") {
+                assert_eq!(transform.transform(n, &QuickJs).unwrap(), expected, "Player {id}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_n_function() {
+        // Called through an array.
+        let transform = NTransform::from_player(PLAYER_A).unwrap();
+        assert_eq!(transform.name, "Yxa");
+        assert!(transform
+            .source
+            .starts_with("var Yxa=function(a){var b=a.split(\"\")"));
+        assert!(transform.source.ends_with("return b.join(\"\")};"));
+
+        // Called by name, with the global check taken out.
+        let transform = NTransform::from_player(PLAYER_B).unwrap();
+        assert_eq!(transform.name, "Bza");
+        assert!(transform
+            .source
+            .starts_with("var Bza=function(a){var b=a.split(\"\")"));
+        assert!(!transform.source.contains("typeof"));
+    }
+
+    #[test]
+    fn test_transform_n() {
+        let transform = NTransform::from_player(PLAYER_A).unwrap();
+        assert_eq!(
+            transform.transform("kzGjXbJFAe5SfE0G", &QuickJs).unwrap(),
+            "LuakGaGfThu0k4P"
+        );
+        assert_eq!(
+            transform.transform("aBcDeFgHiJkLmN_-", &QuickJs).unwrap(),
+            "twhZIV5kIyY7dji"
+        );
+
+        let transform = NTransform::from_player(PLAYER_B).unwrap();
+        assert_eq!(
+            transform.transform("kzGjXbJFAe5SfE0G", &QuickJs).unwrap(),
+            "Mm3N2JltiDihxuAM"
+        );
+        assert_eq!(
+            transform.transform("aBcDeFgHiJkLmN_-", &QuickJs).unwrap(),
+            "jGNDEBw2p8NMTwH2"
+        );
+    }
+
+    #[test]
+    fn test_transform_url() {
+        let transform = NTransform::from_player(PLAYER_A).unwrap();
+        assert_eq!(
+            transform
+                .transform_url(
+                    "https://rr1---sn-example.googlevideo.com/videoplayback?itag=18&n=kzGjXbJFAe5SfE0G&c=WEB",
+                    &QuickJs
+                )
+                .unwrap(),
+            "https://rr1---sn-example.googlevideo.com/videoplayback?itag=18&n=LuakGaGfThu0k4P&c=WEB"
+        );
+        let url = "https://rr1---sn-example.googlevideo.com/videoplayback?itag=18";
+        assert_eq!(transform.transform_url(url, &QuickJs).unwrap(), url);
+    }
+
+    #[test]
+    fn test_n_function_failure() {
+        struct Throws;
+        impl JsEvaluator for Throws {
+            fn call(&self, _: &str, _: &str, argument: &str) -> Result<String, Error> {
+                Ok(format!("{EXCEPTION_PREFIX}AAAAAAAAAAAAAA_{argument}"))
+            }
+        }
+
+        let transform = NTransform::from_player(PLAYER_A).unwrap();
+        assert!(matches!(
+            transform.transform("abc", &Throws),
+            Err(Error::NTransform(_))
+        ));
+    }
+
+    #[test]
+    fn test_changed_player() {
+        let player = PLAYER_A.replace(".get(\"n\")", ".get(\"m\")");
+        assert!(matches!(
+            NTransform::from_player(&player),
+            Err(Error::NTransform(_))
+        ));
+
+        // The function was renamed without the array being updated.
+        let player = PLAYER_A.replace("Yxa=function", "Zxa=function");
+        let Err(Error::NTransform(message)) = NTransform::from_player(&player) else {
+            panic!("Expected the n function to be missing");
+        };
+        assert!(message.contains("'Yxa'"));
+    }
+
+    #[test]
+    fn test_n_call_locals() {
+        // Other names for the locals are fine, as long as they match up.
+        let player = PLAYER_A.replace("(b=a.get(\"n\"))&&(b=oma[0](b)", "(X=a.get(\"n\"))&&(X=oma[0](X)");
+        assert_eq!(NTransform::from_player(&player).unwrap().name, "Yxa");
+        let player = PLAYER_B.replace(
+            "(b=String.fromCharCode(110),c=a.get(b))&&(c=Bza(c)",
+            "($d=String.fromCharCode(110),e=h.get($d))&&(e=Bza(e)",
+        );
+        assert_eq!(NTransform::from_player(&player).unwrap().name, "Bza");
+
+        let player = PLAYER_A.replace("(b=oma[0](b)", "(b=oma[0](c)");
+        assert!(matches!(
+            NTransform::from_player(&player),
+            Err(Error::NTransform(_))
+        ));
+        let player = PLAYER_B.replace("c=a.get(b))&&(c=Bza(c)", "c=a.get(d))&&(c=Bza(c)");
+        assert!(matches!(
+            NTransform::from_player(&player),
+            Err(Error::NTransform(_))
+        ));
+    }
+
+    #[test]
+    fn test_find_closing_brace() {
+        let js = r#"{var a="}",b='{',c=/[}]\//,d=4/2/1;/* } */if(a){b}}"#;
+        assert_eq!(find_closing_brace(js, 0), Some(js.len() - 1));
+        assert_eq!(find_closing_brace("{{}", 0), None);
+    }
+}
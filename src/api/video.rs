@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::{
+    api::{count::Count, formats::StreamingFormats, parse_text},
+    client::Client,
+    endpoints,
+    error::Error,
+    search::{ParseMode, Thumbnail},
+};
+
+/// Everything the `player` endpoint says about a video.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoDetails {
+    pub id:              String,
+    pub title:           String,
+    /// The full description.
+    pub description:     String,
+    /// Zero for live streams that haven't ended.
+    pub length:          Duration,
+    /// The tags the uploader gave the video.
+    pub keywords:        Vec<String>,
+    pub channel:         String,
+    pub channel_id:      String,
+    /// `None` if the response leaves it out.
+    pub view_count:      Option<Count>,
+    /// Out of 5, `YouTube` stopped giving this for most videos.
+    pub average_rating:  Option<f64>,
+    /// The video is live right now.
+    pub is_live:         bool,
+    /// The video is, or was, a live stream.
+    pub is_live_content: bool,
+    pub is_private:      bool,
+    pub is_unlisted:     bool,
+    /// `None` if the response leaves it out.
+    pub is_family_safe:  Option<bool>,
+    pub thumbnails:      Vec<Thumbnail>,
+    /// Empty for videos that can't be streamed (e.g. upcoming or age restricted ones).
+    pub formats:         StreamingFormats,
+}
+
+impl VideoDetails {
+    pub(crate) async fn get(client: &Client, id: &str) -> Result<Self, Error> {
+        let response = endpoints::player(client, id).await?;
+        Self::from_response(&response, client.parse_mode)
+    }
+
+    /// Formats that fail to parse are dealt with according to `parse_mode`.
+    pub(crate) fn from_response(response: &Value, parse_mode: ParseMode) -> Result<Self, Error> {
+        let details = &response["videoDetails"];
+        let microformat = &response["microformat"]["playerMicroformatRenderer"];
+
+        // Videos that can't be watched at all (e.g. removed or private ones) come without
+        // any details, just the reason why.
+        if details.is_null() {
+            let status = &response["playabilityStatus"];
+            return Err(Error::VideoUnavailable {
+                status: status["status"].as_str().unwrap_or("UNKNOWN").to_string(),
+                reason: status["reason"]
+                    .as_str()
+                    .map(str::to_string)
+                    .or_else(|| parse_text(&status["errorScreen"]["playerErrorMessageRenderer"]["reason"]))
+                    .unwrap_or_default(),
+            });
+        }
+
+        let string = |key: &str| {
+            details[key]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| Error::JsonParse(format!("No '{key}' in the video details")))
+        };
+        // Numbers are given as strings.
+        let number = |key: &str| details[key].as_str().and_then(|x| x.parse::<u64>().ok());
+
+        let thumbnails = serde_json::from_value(details["thumbnail"]["thumbnails"].clone())
+            .map_err(|e| Error::JsonParse(e.to_string()))?;
+
+        Ok(Self {
+            id: string("videoId")?,
+            title: string("title")?,
+            description: details["shortDescription"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            length: Duration::from_secs(number("lengthSeconds").unwrap_or_default()),
+            keywords: details["keywords"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|x| x.as_str().map(str::to_string))
+                .collect(),
+            channel: string("author")?,
+            channel_id: string("channelId")?,
+            view_count: number("viewCount").map(Count::exact),
+            average_rating: details["averageRating"].as_f64(),
+            is_live: details["isLive"].as_bool().unwrap_or_default(),
+            is_live_content: details["isLiveContent"].as_bool().unwrap_or_default(),
+            is_private: details["isPrivate"].as_bool().unwrap_or_default(),
+            is_unlisted: microformat["isUnlisted"].as_bool().unwrap_or_default(),
+            is_family_safe: microformat["isFamilySafe"].as_bool(),
+            thumbnails,
+            formats: StreamingFormats::from_response(response, parse_mode)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_video_details() {
+        let response = json!({
+            "playabilityStatus": { "status": "OK" },
+            "videoDetails": {
+                "videoId": "dQw4w9WgXcQ",
+                "title": "Rick Astley - Never Gonna Give You Up (Official Music Video)",
+                "lengthSeconds": "213",
+                "keywords": ["rick astley", "Never Gonna Give You Up"],
+                "channelId": "UCuAXFkgsw1L7xaCfnd5JJOw",
+                "isOwnerViewing": false,
+                "shortDescription": "The official video for “Never Gonna Give You Up” by Rick Astley.\n\nNever: The Autobiography 📚 OUT NOW!",
+                "isCrawlable": true,
+                "thumbnail": { "thumbnails": [
+                    { "url": "https://i.ytimg.com/vi/dQw4w9WgXcQ/default.jpg", "width": 120, "height": 90 },
+                    { "url": "https://i.ytimg.com/vi/dQw4w9WgXcQ/maxresdefault.jpg", "width": 1920, "height": 1080 }
+                ] },
+                "averageRating": 4.75,
+                "allowRatings": true,
+                "viewCount": "1234567890",
+                "author": "Rick Astley",
+                "isPrivate": false,
+                "isUnpluggedCorpus": false,
+                "isLiveContent": false
+            },
+            "microformat": { "playerMicroformatRenderer": {
+                "isFamilySafe": true,
+                "isUnlisted": false,
+                "category": "Music"
+            } }
+        });
+
+        let video = VideoDetails::from_response(&response, ParseMode::Strict).unwrap();
+        assert_eq!(video.id, "dQw4w9WgXcQ");
+        assert_eq!(video.length, Duration::from_secs(213));
+        assert_eq!(video.keywords, ["rick astley", "Never Gonna Give You Up"]);
+        assert!(video.description.ends_with("OUT NOW!"));
+        assert_eq!(video.channel, "Rick Astley");
+        assert_eq!(video.channel_id, "UCuAXFkgsw1L7xaCfnd5JJOw");
+        assert_eq!(video.view_count, Some(Count::exact(1_234_567_890)));
+        assert_eq!(video.average_rating, Some(4.75));
+        assert!(!video.is_live && !video.is_live_content && !video.is_private && !video.is_unlisted);
+        assert_eq!(video.is_family_safe, Some(true));
+        assert_eq!(video.thumbnails.len(), 2);
+        assert_eq!(video.formats, StreamingFormats::default());
+    }
+
+    #[test]
+    fn test_parse_missing_details() {
+        let response = json!({
+            "playabilityStatus": { "status": "OK" },
+            "videoDetails": {
+                "videoId": "dQw4w9WgXcQ",
+                "title": "Rick Astley - Never Gonna Give You Up (Official Music Video)",
+                "channelId": "UCuAXFkgsw1L7xaCfnd5JJOw",
+                "author": "Rick Astley",
+                "thumbnail": { "thumbnails": [] }
+            }
+        });
+
+        let video = VideoDetails::from_response(&response, ParseMode::Strict).unwrap();
+        assert_eq!(video.view_count, None);
+        assert_eq!(video.is_family_safe, None);
+    }
+
+    #[test]
+    fn test_parse_unavailable_video() {
+        let response = json!({ "playabilityStatus": {
+            "status": "ERROR",
+            "reason": "Video unavailable"
+        } });
+        let Err(Error::VideoUnavailable { status, reason }) =
+            VideoDetails::from_response(&response, ParseMode::Strict)
+        else {
+            panic!("Expected the video to be unavailable");
+        };
+        assert_eq!(status, "ERROR");
+        assert_eq!(reason, "Video unavailable");
+    }
+
+    #[tokio::test]
+    async fn test_video() {
+        let client = crate::ClientBuilder::new().build().unwrap();
+        let video = client.video("dQw4w9WgXcQ").await.unwrap();
+        assert_eq!(video.channel_id, "UCuAXFkgsw1L7xaCfnd5JJOw");
+        assert!(video.length > Duration::ZERO);
+    }
+}
@@ -4,13 +4,19 @@ use reqwest::header::{HeaderMap, HeaderValue};
 use crate::{
     config::{self, Locale},
     error::Error,
-    search::SearchResults,
+    hashtag::HashtagFeed,
+    music::{MusicFilter, MusicQueue, MusicSearchResults, QueueSource},
+    search::{ContinuationMode, ParseMode, SearchCursor, SearchFilters, SearchResults},
+    suggestions::{self, MusicSearchSuggestions},
+    video::VideoDetails,
 };
 
 
+#[derive(Default)]
 pub struct ClientBuilder {
-    client_context: config::ClientContext,
-    locale:         config::Locale,
+    variant:    ClientVariant,
+    locale:     config::Locale,
+    parse_mode: ParseMode,
 }
 
 impl ClientBuilder {
@@ -19,7 +25,7 @@ impl ClientBuilder {
 
     #[must_use]
     pub fn variant(mut self, variant: ClientVariant) -> Self {
-        self.client_context = variant.into();
+        self.variant = variant;
         self
     }
 
@@ -30,27 +36,29 @@ impl ClientBuilder {
         self
     }
 
-    pub fn build(self) -> Result<Client, Error> { Client::new(self.client_context, self.locale) }
-}
-
-impl Default for ClientBuilder {
-    fn default() -> Self {
-        Self {
-            client_context: ClientVariant::default().into(),
-            locale:         Locale::from("en-US"),
-        }
+    /// Change what happens when a result fails to parse. By default it's left out and
+    /// recorded in [`SearchResults::parse_errors`].
+    #[must_use]
+    pub fn parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.parse_mode = parse_mode;
+        self
     }
+
+    pub fn build(self) -> Result<Client, Error> { Client::new(self.variant, self.locale, self.parse_mode) }
 }
 
 #[derive(Debug, Clone)]
 pub struct Client {
     http_client:               reqwest::Client,
+    pub(crate) variant:        ClientVariant,
     pub(crate) client_context: config::ClientContext,
     pub(crate) locale:         Locale,
+    pub(crate) parse_mode:     ParseMode,
 }
 
 impl Client {
-    fn new(client_context: config::ClientContext, locale: Locale) -> Result<Self, Error> {
+    pub(crate) fn new(variant: ClientVariant, locale: Locale, parse_mode: ParseMode) -> Result<Self, Error> {
+        let client_context: config::ClientContext = variant.into();
         let mut headers = HeaderMap::new();
         headers.insert("Accept-Encoding", HeaderValue::from_static("gzip, deflate"));
         headers.insert("Accept", HeaderValue::from_static("*/*"));
@@ -93,8 +101,10 @@ impl Client {
 
         Ok(Self {
             http_client,
+            variant,
             client_context,
             locale,
+            parse_mode,
         })
     }
 
@@ -120,14 +130,13 @@ impl Client {
         SearchResults::search(self, query.as_ref(), None).await
     }
 
-    /// Ok, so look. I think `YouTube` uses protobuf for sending their search parameters
-    /// and I have no way of getting their schema so proper parameters in the library
-    /// cannot work unless I were to go through and take note of every single
-    /// combonation of parameters just for them to change it in a few weeks.
+    /// Search with a raw `sp` parameter. Prefer [`Client::search_with_filters`] unless
+    /// you already have a parameter copied from somewhere.
     ///
     /// To get the parameters you need for your specific search you can go to YT and
     /// select the combonation and grab the value of the URL parameter `sp` (e.g.
-    /// `sp=EgQQARgD`). It must remain URL encoded when used as a parameter.
+    /// `sp=EgQQARgD`). It must remain URL encoded when used as a parameter. Use
+    /// [`SearchFilters::from_params`] to see what a parameter actually filters for.
     ///
     /// # Example
     ///
@@ -155,7 +164,130 @@ impl Client {
         SearchResults::search(self, query.as_ref(), Some(params)).await
     }
 
-    /// Continue searching. Returns `true` if more results were able to be found.
+    /// Search YouTube using typed filters instead of a raw `sp` parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error};
+    /// use outertube::search::filter::{DurationFilter, ResultType, SearchFilters};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let filters = SearchFilters::new()
+    ///     .result_type(ResultType::Video)
+    ///     .duration(DurationFilter::Medium);
+    /// let search_results = client
+    ///     .search_with_filters("Linus Cat Tips", &filters)
+    ///     .await?;
+    /// assert!(!search_results.videos.is_empty());
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn search_with_filters(
+        &self,
+        query: impl AsRef<str>,
+        filters: &SearchFilters,
+    ) -> Result<SearchResults, Error> {
+        SearchResults::search(self, query.as_ref(), Some(&filters.to_params())).await
+    }
+
+    /// Run a search again without letting `YouTube` correct the query's spelling, like
+    /// the "Search instead for" link does. Useful when [`SearchResults::correction`]
+    /// shows the results were auto-corrected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let mut search_results = client.search("linus tecc tips").await?;
+    /// if search_results
+    ///     .correction
+    ///     .as_ref()
+    ///     .is_some_and(|x| x.auto_corrected)
+    /// {
+    ///     search_results = client.search_original_query(&search_results).await?;
+    /// }
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn search_original_query(&self, search: &SearchResults) -> Result<SearchResults, Error> {
+        search.search_exact_query(self).await
+    }
+
+    /// Get suggested completions for a partial query in the client's locale, best first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let suggestions = client.search_suggestions("linus tech").await?;
+    /// assert!(!suggestions.is_empty());
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn search_suggestions(&self, prefix: impl AsRef<str>) -> Result<Vec<String>, Error> {
+        suggestions::search_suggestions(self, prefix.as_ref()).await
+    }
+
+    /// Get suggested completions and matching songs, artists, albums, etc. from YouTube
+    /// Music. This always uses the YouTube Music client regardless of the variant the
+    /// client was built with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let suggestions = client.music_search_suggestions("never gonna").await?;
+    /// assert!(!suggestions.queries.is_empty());
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn music_search_suggestions(
+        &self,
+        prefix: impl AsRef<str>,
+    ) -> Result<MusicSearchSuggestions, Error> {
+        MusicSearchSuggestions::get(self, prefix.as_ref()).await
+    }
+
+    /// Get the page of results a [`SearchCursor`] points at. The search continues with
+    /// the client variant and locale it was started with, even if they differ from
+    /// this client's. Returns `None` if there are no more results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let search_results = client.search("Linus Cat Tips").await?;
+    /// let checkpoint = serde_json::to_string(&search_results.cursor()).unwrap();
+    ///
+    /// // ... after a restart
+    /// let cursor = serde_json::from_str(&checkpoint).unwrap();
+    /// if let Some(next_page) = client.resume_search(&cursor).await? {
+    ///     assert!(!next_page.items.is_empty());
+    /// }
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn resume_search(&self, cursor: &SearchCursor) -> Result<Option<SearchResults>, Error> {
+        SearchResults::resume(self, cursor).await
+    }
+
+    /// Continue searching, replacing the current results with the next page. Returns
+    /// `true` if more results were able to be found. See
+    /// [`Client::continue_search_with_mode`] to keep the current results.
     ///
     /// # Example
     ///
@@ -174,7 +306,154 @@ impl Client {
     /// ```
     #[inline]
     pub async fn continue_search(&self, search: &mut SearchResults) -> Result<bool, Error> {
-        search.continue_search(self).await
+        search.continue_search(self, ContinuationMode::Replace).await
+    }
+
+    /// Continue searching, either replacing the current results with the next page or
+    /// adding the next page to them. Results already seen on a previous page are left out
+    /// either way. Returns `true` if another page was found, even if everything on it was
+    /// already seen.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error, search::ContinuationMode};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let mut search_results = client.search("Linus Cat Tips").await?;
+    /// let first_page = search_results.videos.len();
+    /// if client
+    ///     .continue_search_with_mode(&mut search_results, ContinuationMode::Accumulate)
+    ///     .await?
+    /// {
+    ///     assert!(search_results.videos.len() >= first_page);
+    /// }
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn continue_search_with_mode(
+        &self,
+        search: &mut SearchResults,
+        mode: ContinuationMode,
+    ) -> Result<bool, Error> {
+        search.continue_search(self, mode).await
+    }
+
+    /// Get the details of a single video (title, description, length, etc.) by its ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let video = client.video("dQw4w9WgXcQ").await?;
+    /// assert_eq!(video.channel, "Rick Astley");
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn video(&self, id: impl AsRef<str>) -> Result<VideoDetails, Error> {
+        VideoDetails::get(self, id.as_ref()).await
+    }
+
+    /// Get the videos and shorts on a hashtag's page (`/hashtag/<tag>`). The leading '#'
+    /// is optional.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let feed = client.hashtag("#minecraft").await?;
+    /// assert!(!feed.videos.is_empty() || !feed.shorts.is_empty());
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn hashtag(&self, tag: impl AsRef<str>) -> Result<HashtagFeed, Error> {
+        HashtagFeed::get(self, tag.as_ref()).await
+    }
+
+    /// Search YouTube Music, optionally for just one kind of result. This always uses the
+    /// YouTube Music client regardless of the variant the client was built with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error};
+    /// use outertube::music::{MusicFilter, MusicSearchItem};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let results = client
+    ///     .music_search("never gonna give you up", MusicFilter::Songs)
+    ///     .await?;
+    /// assert!(matches!(results.items[0], MusicSearchItem::Song(_)));
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn music_search(
+        &self,
+        query: impl AsRef<str>,
+        filter: MusicFilter,
+    ) -> Result<MusicSearchResults, Error> {
+        MusicSearchResults::search(self, query.as_ref(), filter).await
+    }
+
+    /// Resolve songs and videos, or a whole playlist, into YouTube Music queue tracks in
+    /// one request. This always uses the YouTube Music client regardless of the variant
+    /// the client was built with. Tracks that fail to parse are dealt with according to
+    /// the client's [`ParseMode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error};
+    /// use outertube::music::QueueSource;
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let video_ids = vec!["lYBUbBu4W08".to_string(), "yPYZpwSpKmA".to_string()];
+    /// let queue = client.music_queue(&QueueSource::Videos(video_ids)).await?;
+    /// assert_eq!(queue.tracks.len(), 2);
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn music_queue(&self, source: &QueueSource) -> Result<MusicQueue, Error> {
+        MusicQueue::get(self, source).await
+    }
+
+    /// Get the next page of a hashtag feed, either replacing the current videos and
+    /// shorts or adding to them. Returns `false` if there are no more pages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use outertube::{ClientBuilder, error::Error, search::ContinuationMode};
+    /// # tokio_test::block_on(async {
+    /// # let client = ClientBuilder::new().build()?;
+    /// let mut feed = client.hashtag("minecraft").await?;
+    /// while feed.videos.len() < 100 {
+    ///     if !client
+    ///         .continue_hashtag(&mut feed, ContinuationMode::Accumulate)
+    ///         .await?
+    ///     {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[inline]
+    pub async fn continue_hashtag(
+        &self,
+        feed: &mut HashtagFeed,
+        mode: ContinuationMode,
+    ) -> Result<bool, Error> {
+        feed.continue_feed(self, mode).await
     }
 }
 
@@ -222,4 +501,27 @@ mod tests {
         assert!(search_results.shorts.is_empty() && search_results.channels.is_empty());
         assert!(!search_results.videos.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_search_with_filters() {
+        use crate::search::filter::{DurationFilter, ResultType};
+
+        let client = ClientBuilder::new().build().unwrap();
+        let filters = SearchFilters::new()
+            .result_type(ResultType::Video)
+            .duration(DurationFilter::Medium);
+        let search_results = client
+            .search_with_filters("Linus Cat Tips", &filters)
+            .await
+            .unwrap();
+        assert!(search_results.shorts.is_empty() && search_results.channels.is_empty());
+        assert!(!search_results.videos.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_suggestions() {
+        let client = ClientBuilder::new().locale("de-DE").build().unwrap();
+        let suggestions = client.search_suggestions("linus tech").await.unwrap();
+        assert!(!suggestions.is_empty());
+    }
 }
@@ -1,8 +1,10 @@
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 const REFERER_YOUTUBE: &str = "https://www.youtube.com/";
 const REFERER_YOUTUBE_MOBILE: &str = "https://m.youtube.com/";
-const REFERER_YOUTUBE_MUSIC: &str = "https://music.youtube.com/";
+pub(crate) const REFERER_YOUTUBE_MUSIC: &str = "https://music.youtube.com/";
 const REFERER_YOUTUBE_KIDS: &str = "https://www.youtubekids.com/";
 const REFERER_YOUTUBE_STUDIO: &str = "https://studio.youtube.com/";
 const REFERER_YOUTUBE_ANALYTICS: &str = "https://analytics.youtube.com/";
@@ -27,11 +29,14 @@ const USER_AGENT_GOOGLE_ASSISTANT: &str =
     "Mozilla/5.0 (Linux; Android 11; Pixel 2; DuplexWeb-Google/1.0) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/86.0.4240.193 Mobile Safari/537.36";
 
 pub struct Config {
-    pub(crate) base_url:   &'static str,
-    client_configurations: &'static [ClientContext],
+    pub(crate) base_url:        &'static str,
+    /// Used instead of `base_url` by the YouTube Music clients.
+    pub(crate) music_base_url:  &'static str,
+    pub(crate) suggestions_url: &'static str,
+    client_configurations:      &'static [ClientContext],
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[repr(usize)]
 pub enum ClientVariant {
     /// The YouTube web client found at `www.youtube.com`
@@ -103,7 +108,7 @@ impl ClientVariant {
             return Err(format!("{value} is not a variant of ClientVariant"));
         }
 
-        Ok(unsafe { std::mem::transmute(value) })
+        Ok(unsafe { std::mem::transmute::<usize, ClientVariant>(value) })
     }
 }
 
@@ -126,12 +131,16 @@ pub(crate) struct ClientContext {
     pub(super) referer:    Option<&'static str>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Locale {
     pub(crate) hl: String,
     pub(crate) gl: Option<String>,
 }
 
+impl Default for Locale {
+    fn default() -> Self { Self::from("en-US") }
+}
+
 impl From<&str> for Locale {
     fn from(value: &str) -> Self {
         if let Some((hl, gl)) = value.split_once('-') {
@@ -162,6 +171,8 @@ impl fmt::Display for Locale {
 pub(crate) const CONFIG: Config = Config {
     // base_url:              "https://youtubei.googleapis.com/youtubei/v1/",
     base_url:              "https://www.youtube.com/youtubei/v1/",
+    music_base_url:        "https://music.youtube.com/youtubei/v1/",
+    suggestions_url:       "https://suggestqueries-clients6.youtube.com/complete/search",
     client_configurations: &[
         ClientContext {
             id:         1,
@@ -17,6 +17,24 @@ pub enum Error {
 
     #[error("YouTube returned JSON that couldn't be parsed: {0}")]
     JsonParse(String),
+
+    #[error("Failed to parse a result: {0}")]
+    ItemParse(Box<crate::search::ParseError>),
+
+    #[error("Couldn't decipher the signature: {0}")]
+    Cipher(String),
+
+    #[error("Couldn't transform the n parameter: {0}")]
+    NTransform(String),
+
+    #[error("Failed to run JavaScript: {0}")]
+    JsEvaluation(String),
+
+    #[error("The video is unavailable ({status}): {reason}")]
+    VideoUnavailable { status: String, reason: String },
+
+    #[error("Invalid search parameters: {0}")]
+    InvalidSearchParams(String),
 }
 
 impl From<reqwest::Error> for Error {
@@ -0,0 +1,13 @@
+/*
+ Synthetic player for outertube's offline tests. It is not a copy of YouTube's base.js,
+ only the parts the signature deciphering looks for, laid out the way base.js lays them
+ out (minified names, a helper object of transforms, and a function calling them).
+*/
+var _yt_player={};(function(g){var window=this;var aa=function(a){var b=0;return function(){return b<a.length?{done:!1,value:a[b++]}:{done:!0}}};
+var XE={Kx:function(a,b){a.splice(0,b)},
+nC:function(a){a.reverse()},
+Ys:function(a,b){var c=a[0];a[0]=a[b%a.length];a[b%a.length]=c}};
+var Vta=function(a,b){this.j=a;this.B=b||null};
+Fta=function(a){a=a.split("");XE.Ys(a,49);XE.nC(a,10);XE.Kx(a,3);XE.Ys(a,6);XE.nC(a,34);XE.Kx(a,2);return a.join("")};
+g.Wt=function(a,b){a.set(b.sp||"signature",Fta(b.s));return a};
+})(_yt_player);
@@ -0,0 +1,15 @@
+/*
+ Synthetic player for outertube's offline tests. It is not a copy of YouTube's base.js,
+ only the parts the `n` transform looks for, laid out the way base.js lays them out
+ (a minified `n` function called through an array it's the only thing in).
+*/
+var _yt_player={};(function(g){var window=this;var aa=function(a){var b=0;return function(){return b<a.length?{done:!1,value:a[b++]}:{done:!0}}};
+var Vta=function(a,b){this.j=a;this.B=b||null};
+g.Ut=function(a){var b;a.D&&(b=a.get("n"))&&(b=oma[0](b),a.set("n",b),oma.length||Yxa(""));return a};
+Yxa=function(a){var b=a.split(""),c=[function(d,e){e=(e%d.length+d.length)%d.length;d.splice(-e).reverse().forEach(function(f){d.unshift(f)})},
+-1573438432,"push",/,,[/,913,/](,)}/,function(d,e){e=(e%d.length+d.length)%d.length;var f=d[0];d[0]=d[e];d[e]=f},
+b,function(d){d.reverse()},function(d,e){for(var f=64,h=[];++f-h.length-32;)switch(f){case 58:f=96;continue;case 91:f=44;break;case 65:f=47;continue;case 46:f=153;case 123:f-=58;default:h.push(String.fromCharCode(f))}d.forEach(function(l,m,n){this.push(n[m]=h[(h.indexOf(l)-h.indexOf(this[m])+m-32+f--)%h.length])},e.split(""))},
+"{}",null,function(d,e){e=(e%d.length+d.length)%d.length;d.splice(e,1)},"bRr1yf"];c[9]=c;
+try{c[4](c[5],3),c[0](c[5],-7),c[7](c[5],c[11]),c[6](c[5]),c[10](c[5],21),c[4](c[5],c[1]),c[0](c[5],2)}catch(d){return"enhanced_except_AAAAAAAAAAAAAA_"+a}return b.join("")};
+var oma=[Yxa];
+})(_yt_player);
@@ -0,0 +1,16 @@
+/*
+ Synthetic player for outertube's offline tests. It is not a copy of YouTube's base.js,
+ only the parts the `n` transform looks for, laid out the way base.js lays them out.
+ This one looks `n` up with String.fromCharCode(110), calls the function by name, and
+ checks a global is defined first, which newer players do.
+*/
+var _yt_player={};(function(g){var window=this;'use strict';
+var $r={wS:function(a,b){var c=a[0];a[0]=a[b%a.length];a[b%a.length]=c}};
+g.pk=function(a){return a};
+g.Yq=function(a){var b,c;(b=String.fromCharCode(110),c=a.get(b))&&(c=Bza(c),a.set(b,c));return a};
+var Bza=function(a){if(typeof $r==="undefined")return a;var b=a.split(""),c=[function(d){d.reverse()},
+"length",function(d,e){d.push(e)},function(d,e){e=(e%d.length+d.length)%d.length;d.splice(0,1,d.splice(e,1,d[0])[0])},
+b,function(d,e){for(var f="ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",h=e.length,l=0;l<d.length;l++)d[l]=f[(f.indexOf(d[l])+e.charCodeAt(l%h))%64]},
+'}"{',function(d,e){e=(e%d.length+d.length)%d.length;d.splice(-e).reverse().forEach(function(f){d.unshift(f)})},"Kq9"];
+try{c[3](c[4],17),c[5](c[4],c[8]),c[0](c[4]),c[7](c[4],5),c[3](c[4],-2),c[5](c[4],"x")}catch(d){return"enhanced_except_BBBBBBBBBBBBBB_"+a}return b.join("")};
+})(_yt_player);